@@ -0,0 +1,44 @@
+use chrono::{Duration, Local};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use speakable_time::{prelude::*, DEFAULT_TRANSLATION};
+
+fn rows(count: i64) -> Vec<chrono::DateTime<Local>> {
+    let now = Local::now();
+    (0..count)
+        .map(|x| now - Duration::seconds(x * 7919 % (2 * 365 * 24 * 60 * 60)))
+        .collect()
+}
+
+fn batch(c: &mut Criterion) {
+    let approx = approximator!(
+        CoarseRoundFormat::default(),
+        ApproximateFilter::TopRounds(2),
+        ApproximateFilter::Relative
+    );
+
+    let mut group = c.benchmark_group("render");
+    for count in [1000, 50000] {
+        let rows = rows(count);
+
+        group.bench_with_input(BenchmarkId::new("from_now", count), &rows, |b, rows| {
+            b.iter(|| {
+                for dt in rows {
+                    criterion::black_box(from_now!(*dt, approx).unwrap());
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", count), &rows, |b, rows| {
+            b.iter(|| {
+                let mut batch = approx.batch(Local::now());
+                for dt in rows {
+                    criterion::black_box(batch.render(*dt, &DEFAULT_TRANSLATION).unwrap());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, batch);
+criterion_main!(benches);
@@ -1,147 +1,1010 @@
 use super::FormatGenerator;
 use crate::{
     approximate::{ApproximateState, StateCollection},
-    enums::Words,
+    enums::{ListKind, ListStyle, ListWidth, Pattern},
     time_boundary::TimeBoundary,
+    translator::{NumberForm, Template},
 };
+use anyhow::{anyhow, Result};
+use chrono::Duration;
+use std::borrow::Cow;
+
+/// The states the duration formats here keep, the values and whether they are in the past, with
+/// the bookkeeping their [FormatGenerator] implementations share.
+#[derive(Clone, Debug, Default)]
+struct DurationStates {
+    states: Vec<ApproximateState>,
+    parsed: bool,
+}
+
+impl DurationStates {
+    fn keeps(state: &ApproximateState) -> bool {
+        matches!(
+            state,
+            ApproximateState::Value(..) | ApproximateState::InPast(_)
+        )
+    }
+
+    fn add(&mut self, states: StateCollection) {
+        self.states.extend(states.0.into_iter().filter(Self::keeps));
+    }
+
+    fn extend(&mut self, states: &StateCollection) {
+        self.states
+            .extend(states.iter().filter(|state| Self::keeps(state)).cloned());
+    }
+
+    fn clear(&mut self) {
+        self.states.clear();
+        self.parsed = false;
+    }
+}
+
+impl std::ops::Deref for DurationStates {
+    type Target = [ApproximateState];
+
+    fn deref(&self) -> &Self::Target {
+        &self.states
+    }
+}
+
+impl<'a> IntoIterator for &'a DurationStates {
+    type Item = &'a ApproximateState;
+    type IntoIter = std::slice::Iter<'a, ApproximateState>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.states.iter()
+    }
+}
 
 /// This format is based off of the `fancy_duration` crate and generates durations in times like
-/// `2y1h15m`.
-#[derive(Clone, Default)]
+/// `2y1h15m`. Months are written with a separate marker (`mo` by default) so that they can be told
+/// apart from minutes; see [FancyDurationFormat::with_month_marker] and
+/// [FancyDurationFormat::parse].
+///
+/// These markers are not translated. For output in the user's language, choose a unit width
+/// with [FancyDurationFormat::with_unit_width]: "2 hours ago", "2 hr. ago" or "2h ago" in
+/// English, and "2時間前" in Japanese.
+#[derive(Clone)]
 pub struct FancyDurationFormat {
-    formats: Vec<ApproximateState>,
-    parsed: bool,
+    formats: DurationStates,
+    month_marker: String,
+    width: Option<ListWidth>,
+    separator: Option<String>,
+}
+
+impl Default for FancyDurationFormat {
+    fn default() -> Self {
+        Self {
+            formats: DurationStates::default(),
+            month_marker: "mo".to_string(),
+            width: None,
+            separator: None,
+        }
+    }
+}
+
+impl FancyDurationFormat {
+    /// Use a different marker for months. Note that using `m` here makes the output ambiguous
+    /// with minutes, and [FancyDurationFormat::parse] will refuse to parse such strings.
+    pub fn with_month_marker(mut self, marker: &str) -> Self {
+        self.month_marker = marker.to_string();
+        self
+    }
+
+    /// Write units with the locale's abbreviations for a [ListWidth] instead of the fixed
+    /// markers: full words when wide, `hour_short` when short and `hour_narrow` when narrow.
+    /// Units are joined with the locale's unit list pattern of the same width, unless a
+    /// separator is given with [FancyDurationFormat::with_separator]. Such output cannot be read
+    /// back with [FancyDurationFormat::parse].
+    pub fn with_unit_width(mut self, width: ListWidth) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Join localized units with a fixed separator, such as `" "` or `", "`, instead of the
+    /// locale's unit list pattern. The fixed markers are always written without one.
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = Some(separator.to_string());
+        self
+    }
+
+    /// The marker written after a value of the given boundary.
+    pub fn marker(&self, relative: &TimeBoundary) -> &str {
+        match relative {
+            TimeBoundary::Month => &self.month_marker,
+            _ => Self::fixed_marker(relative),
+        }
+    }
+
+    fn fixed_marker(relative: &TimeBoundary) -> &'static str {
+        match relative {
+            TimeBoundary::Year => "y",
+            TimeBoundary::Month => "mo",
+            TimeBoundary::Week => "w",
+            TimeBoundary::Day => "d",
+            TimeBoundary::Hour => "h",
+            TimeBoundary::Minute => "m",
+            TimeBoundary::Second => "s",
+        }
+    }
+
+    /// Parse a string like `2y1mo15m3s` back into [ApproximateState::Value] states, in the order
+    /// they were written. The untranslated `%{in} ` prefix and ` %{ago}` suffix produced by
+    /// [FormatGenerator::format] are also understood and yield an [ApproximateState::InPast].
+    pub fn parse(&self, s: &str) -> Result<StateCollection> {
+        let mut states = StateCollection::default();
+        let mut s = s.trim();
+
+        if let Some(rest) = s.strip_prefix("%{in}") {
+            states.push(ApproximateState::InPast(false));
+            s = rest.trim_start();
+        } else if let Some(rest) = s.strip_suffix("%{ago}") {
+            states.push(ApproximateState::InPast(true));
+            s = rest.trim_end();
+        }
+
+        if s.is_empty() {
+            return Err(anyhow!("Invalid duration (no values)"));
+        }
+
+        let mut chars = s.chars().peekable();
+        while chars.peek().is_some() {
+            let mut number = String::new();
+            while let Some(ch) = chars.next_if(|ch| ch.is_ascii_digit()) {
+                number.push(ch);
+            }
+
+            let mut marker = String::new();
+            while let Some(ch) = chars.next_if(|ch| !ch.is_ascii_digit()) {
+                marker.push(ch);
+            }
+
+            if number.is_empty() {
+                return Err(anyhow!(
+                    "Invalid duration (marker '{}' has no value)",
+                    marker
+                ));
+            }
+
+            let mut matches = TimeBoundary::all()
+                .into_iter()
+                .filter(|relative| self.marker(relative) == marker);
+
+            let relative = match (matches.next(), matches.next()) {
+                (Some(relative), None) => relative,
+                (Some(_), Some(_)) => {
+                    return Err(anyhow!(
+                        "Invalid duration (marker '{}' is ambiguous)",
+                        marker
+                    ))
+                }
+                (None, _) => return Err(anyhow!("Invalid duration (unknown marker '{}')", marker)),
+            };
+
+            states.push(ApproximateState::Value(relative, number.parse()?));
+        }
+
+        Ok(states)
+    }
+
+    /// Parse a string like `2y1mo15m3s` into a [chrono::Duration]. Months are 30 days and years
+    /// 365 days. Durations marked as being in the past are negative.
+    pub fn parse_duration(&self, s: &str) -> Result<Duration> {
+        signed_duration(self.parse(s)?.iter())
+    }
+}
+
+/// Sum the [ApproximateState::Value] states into a duration, negating it if the states say it is
+/// in the past. An error is returned if the duration is out of range.
+fn signed_duration<'a>(states: impl Iterator<Item = &'a ApproximateState>) -> Result<Duration> {
+    let mut duration = Duration::zero();
+    let mut in_past = false;
+
+    for state in states {
+        match state {
+            ApproximateState::Value(relative, time) => {
+                duration = relative
+                    .duration(*time)
+                    .and_then(|value| duration.checked_add(&value))
+                    .ok_or_else(|| anyhow!("Invalid duration (out of range)"))?;
+            }
+            ApproximateState::InPast(past) => in_past = *past,
+            _ => {}
+        }
+    }
+
+    Ok(if in_past { -duration } else { duration })
+}
+
+/// Write a count of nanoseconds as a decimal number of the provided unit, trimming trailing
+/// zeros: 1500 nanoseconds in microseconds is `1.5`.
+fn decimal(nanos: i128, unit: i128) -> String {
+    let whole = nanos / unit;
+    let fraction = nanos % unit;
+
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let width = unit.to_string().len() - 1;
+    let fraction = format!("{:0width$}", fraction, width = width);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// Parse a sequence of decimal numbers followed by units, like `1h2m3.5s`, into a duration. Each
+/// unit is paired with its length in nanoseconds. Whitespace between numbers and units is only
+/// permitted if `spaces` is set, and `default` is the unit used when one is omitted.
+fn parse_units(
+    s: &str,
+    units: &[(&str, i128)],
+    spaces: bool,
+    default: Option<i128>,
+) -> Result<i128> {
+    let mut total: i128 = 0;
+    let mut chars = s.chars().peekable();
+
+    loop {
+        if spaces {
+            while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+        }
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut whole = String::new();
+        while let Some(ch) = chars.next_if(|ch| ch.is_ascii_digit()) {
+            whole.push(ch);
+        }
+
+        let mut fraction = String::new();
+        if chars.next_if_eq(&'.').is_some() {
+            while let Some(ch) = chars.next_if(|ch| ch.is_ascii_digit()) {
+                fraction.push(ch);
+            }
+        }
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(anyhow!("Invalid duration (expected a number in '{}')", s));
+        }
+
+        if spaces {
+            while chars.next_if(|ch| *ch == ' ').is_some() {}
+        }
+
+        let mut unit = String::new();
+        while let Some(ch) =
+            chars.next_if(|ch| !ch.is_ascii_digit() && *ch != '.' && !ch.is_whitespace())
+        {
+            unit.push(ch);
+        }
+
+        let length = if unit.is_empty() {
+            default.ok_or_else(|| anyhow!("Invalid duration (missing unit in '{}')", s))?
+        } else {
+            units
+                .iter()
+                .find(|(name, _)| *name == unit)
+                .map(|(_, length)| *length)
+                .ok_or_else(|| anyhow!("Invalid duration (unknown unit '{}')", unit))?
+        };
+
+        let out_of_range = || anyhow!("Invalid duration (out of range)");
+        let whole: i128 = if whole.is_empty() { 0 } else { whole.parse()? };
+        total = whole
+            .checked_mul(length)
+            .and_then(|x| total.checked_add(x))
+            .ok_or_else(out_of_range)?;
+
+        // only as much precision as a nanosecond allows is kept.
+        let mut scale = length;
+        for digit in fraction.chars() {
+            scale /= 10;
+            total = total
+                .checked_add(digit.to_digit(10).unwrap() as i128 * scale)
+                .ok_or_else(out_of_range)?;
+        }
+    }
+
+    Ok(total)
+}
+
+/// Convert a count of nanoseconds into a [Duration], failing if it does not fit.
+fn nanoseconds(nanos: i128) -> Result<Duration> {
+    Ok(Duration::nanoseconds(
+        nanos
+            .try_into()
+            .map_err(|_| anyhow!("Invalid duration (out of range)"))?,
+    ))
 }
 
 impl FormatGenerator for FancyDurationFormat {
     fn set_is_parsed(&mut self) {
-        self.parsed = true;
+        self.formats.parsed = true;
     }
 
     fn is_parsed(&self) -> bool {
-        self.parsed
+        self.formats.parsed
     }
 
     fn add(&mut self, states: StateCollection) {
-        for state in states.0 {
-            match state {
-                ApproximateState::Value(..) | ApproximateState::InPast(_) => {
-                    self.formats.push(state)
+        self.formats.add(states)
+    }
+
+    fn extend(&mut self, states: &StateCollection) {
+        self.formats.extend(states)
+    }
+
+    fn clear(&mut self) -> bool {
+        self.formats.clear();
+        true
+    }
+
+    fn format(&self) -> String {
+        self.template().to_string()
+    }
+
+    fn template_into(&self, template: &mut Template) {
+        let in_past = self.formats.iter().rev().find_map(|format| match format {
+            ApproximateState::InPast(past) => Some(*past),
+            _ => None,
+        });
+
+        let values = self.formats.iter().filter_map(|format| match format {
+            ApproximateState::Value(relative, time) => Some((relative, *time)),
+            _ => None,
+        });
+
+        let mut duration = Template::default();
+        match self.width {
+            None => {
+                for (relative, time) in values {
+                    duration.number(time);
+                    duration.literal(match relative {
+                        TimeBoundary::Month => Cow::Owned(self.month_marker.clone()),
+                        _ => Cow::Borrowed(Self::fixed_marker(relative)),
+                    });
                 }
-                _ => {}
             }
+            Some(width) => {
+                let items = values.map(|(relative, time)| {
+                    let mut item = Template::default();
+                    item.abbreviated(time, relative.clone(), width);
+                    item
+                });
+
+                match &self.separator {
+                    Some(separator) => {
+                        for (x, item) in items.enumerate() {
+                            if x > 0 {
+                                duration.literal(separator.clone());
+                            }
+                            duration.append(item);
+                        }
+                    }
+                    None => duration.list(ListStyle::new(ListKind::Unit, width), items.collect()),
+                }
+            }
+        }
+
+        match in_past {
+            Some(true) => template.phrase(Pattern::ShortPast, duration),
+            Some(false) => template.phrase(Pattern::ShortFuture, duration),
+            None => template.phrase(Pattern::Standalone, duration),
         }
     }
+}
+
+/// This format is verbose and will generate strings like "2 years, 5 months, and 3 days ago".
+#[derive(Clone, Default)]
+pub struct CoarseRoundFormat {
+    formats: DurationStates,
+    list_style: ListStyle,
+    spell_below: i64,
+    articles: bool,
+}
+
+impl CoarseRoundFormat {
+    /// Join the units with a different [ListStyle]. The default is a wide conjunction list, "2
+    /// years, 5 months and 3 days"; a narrow unit list gives "2 years 5 months 3 days".
+    pub fn with_list_style(mut self, style: ListStyle) -> Self {
+        self.list_style = style;
+        self
+    }
+
+    /// Spell out values below `limit` in words, giving "nine days ago" but "45 days ago" for a
+    /// limit of 10. Locales without cardinal rules keep digits; see
+    /// [Translator::cardinal](crate::translator::Translator::cardinal).
+    pub fn with_spelling(mut self, limit: i64) -> Self {
+        self.spell_below = limit;
+        self
+    }
+
+    /// Write a single unit with its indefinite article, "an hour ago" instead of "1 hour ago".
+    /// This takes precedence over [CoarseRoundFormat::with_spelling].
+    pub fn with_articles(mut self) -> Self {
+        self.articles = true;
+        self
+    }
+}
+
+impl FormatGenerator for CoarseRoundFormat {
+    fn set_is_parsed(&mut self) {
+        self.formats.parsed = true;
+    }
+
+    fn is_parsed(&self) -> bool {
+        self.formats.parsed
+    }
+
+    fn add(&mut self, states: StateCollection) {
+        self.formats.add(states)
+    }
+
+    fn extend(&mut self, states: &StateCollection) {
+        self.formats.extend(states)
+    }
+
+    fn clear(&mut self) -> bool {
+        self.formats.clear();
+        true
+    }
 
     fn format(&self) -> String {
-        let mut in_past: Option<bool> = None;
-        let mut s = String::new();
+        self.template().to_string()
+    }
 
+    fn template_into(&self, template: &mut Template) {
+        let mut in_past: Option<bool> = None;
+        let mut items = Vec::new();
         for format in &self.formats {
             match format {
                 ApproximateState::InPast(past) => in_past = Some(*past),
                 ApproximateState::Value(relative, time) => {
-                    let rs = match relative {
-                        TimeBoundary::Year => "y",
-                        TimeBoundary::Month => "m",
-                        TimeBoundary::Week => "w",
-                        TimeBoundary::Day => "d",
-                        TimeBoundary::Hour => "h",
-                        TimeBoundary::Minute => "m",
-                        TimeBoundary::Second => "s",
+                    let form = if self.articles && *time == 1 {
+                        NumberForm::Article
+                    } else if (0..self.spell_below).contains(time) {
+                        NumberForm::Spelled
+                    } else {
+                        NumberForm::Digits
                     };
 
-                    s += &format!("{}{}", time, rs);
+                    let mut item = Template::default();
+                    item.quantity_as(*time, relative.clone(), form);
+                    items.push(item);
                 }
                 _ => {}
             }
         }
 
-        if let Some(past) = in_past {
-            if past {
-                format!("{} %{{ago}}", s)
-            } else {
-                format!("%{{in}} {}", s)
-            }
-        } else {
-            s
+        let mut duration = Template::default();
+        duration.list(self.list_style, items);
+
+        match in_past {
+            Some(true) => template.phrase(Pattern::Past, duration),
+            Some(false) => template.phrase(Pattern::Future, duration),
+            None => template.phrase(Pattern::Standalone, duration),
         }
     }
 }
 
-/// This format is verbose and will generate strings like "2 years, 5 months, and 3 days ago".
+/// How [Iso8601Format] represents [ApproximateState::InPast].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Iso8601Sign {
+    /// Past durations are prefixed with `-`, future durations are unsigned: `-P2D`.
+    #[default]
+    Negative,
+    /// Past durations are prefixed with `-` and future durations with `+`: `+P2D`.
+    Explicit,
+    /// Wrap the duration in the same `%{in}` and `%{ago}` words as [FancyDurationFormat].
+    Words,
+    /// Drop the sign entirely.
+    Ignore,
+}
+
+/// This format generates ISO 8601 durations like `P1Y2M3DT4H5M6S` or `P2W`. Weeks are only
+/// written on their own; when they are combined with other values they are folded into days, as
+/// ISO 8601 does not permit mixing them. Use [Iso8601Format::parse] to read these durations back
+/// into [ApproximateState] values.
 #[derive(Clone, Default)]
-pub struct CoarseRoundFormat {
-    formats: Vec<ApproximateState>,
-    parsed: bool,
+pub struct Iso8601Format {
+    formats: DurationStates,
+    sign: Iso8601Sign,
 }
 
-impl FormatGenerator for CoarseRoundFormat {
+impl Iso8601Format {
+    /// Choose how the sign of the duration is written.
+    pub fn with_sign(mut self, sign: Iso8601Sign) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Parse an ISO 8601 duration into [ApproximateState::Value] states, ordered from the most
+    /// significant boundary. A leading `-` or `+` (or the `%{ago}` and `%{in}` words) yields an
+    /// [ApproximateState::InPast]. Fractional values are not supported, nor are values too large
+    /// for a [Duration].
+    pub fn parse(&self, s: &str) -> Result<StateCollection> {
+        let mut states = StateCollection::default();
+        let mut s = s.trim();
+
+        if let Some(rest) = s.strip_prefix("%{in}") {
+            states.push(ApproximateState::InPast(false));
+            s = rest.trim_start();
+        } else if let Some(rest) = s.strip_suffix("%{ago}") {
+            states.push(ApproximateState::InPast(true));
+            s = rest.trim_end();
+        } else if let Some(rest) = s.strip_prefix('-') {
+            states.push(ApproximateState::InPast(true));
+            s = rest;
+        } else if let Some(rest) = s.strip_prefix('+') {
+            states.push(ApproximateState::InPast(false));
+            s = rest;
+        }
+
+        let s = s
+            .strip_prefix('P')
+            .ok_or_else(|| anyhow!("Invalid ISO 8601 duration (missing 'P')"))?;
+
+        let (date, time) = match s.split_once('T') {
+            Some((_, "")) => return Err(anyhow!("Invalid ISO 8601 duration (empty time part)")),
+            Some((date, time)) => (date, time),
+            None => (s, ""),
+        };
+
+        let mut found = false;
+        for (part, designators) in [
+            (
+                date,
+                [
+                    ('Y', TimeBoundary::Year),
+                    ('M', TimeBoundary::Month),
+                    ('W', TimeBoundary::Week),
+                    ('D', TimeBoundary::Day),
+                ]
+                .as_slice(),
+            ),
+            (
+                time,
+                [
+                    ('H', TimeBoundary::Hour),
+                    ('M', TimeBoundary::Minute),
+                    ('S', TimeBoundary::Second),
+                ]
+                .as_slice(),
+            ),
+        ] {
+            let mut designators = designators.iter();
+            let mut number = String::new();
+
+            for ch in part.chars() {
+                if ch.is_ascii_digit() {
+                    number.push(ch);
+                    continue;
+                }
+
+                if ch == '.' || ch == ',' {
+                    return Err(anyhow!(
+                        "Invalid ISO 8601 duration (fractional values are not supported)"
+                    ));
+                }
+
+                if number.is_empty() {
+                    return Err(anyhow!(
+                        "Invalid ISO 8601 duration (designator '{}' has no value)",
+                        ch
+                    ));
+                }
+
+                // designators must appear in order, so skip forward until we find this one.
+                let relative = loop {
+                    match designators.next() {
+                        Some((designator, relative)) if *designator == ch => break relative,
+                        Some(_) => {}
+                        None => {
+                            return Err(anyhow!(
+                                "Invalid ISO 8601 duration (unexpected designator '{}')",
+                                ch
+                            ))
+                        }
+                    }
+                };
+
+                let value = number.parse()?;
+                if relative.duration(value).is_none() {
+                    return Err(anyhow!("Invalid ISO 8601 duration (out of range)"));
+                }
+
+                states.push(ApproximateState::Value(relative.clone(), value));
+                number.truncate(0);
+                found = true;
+            }
+
+            if !number.is_empty() {
+                return Err(anyhow!(
+                    "Invalid ISO 8601 duration (value '{}' has no designator)",
+                    number
+                ));
+            }
+        }
+
+        if !found {
+            return Err(anyhow!("Invalid ISO 8601 duration (no values)"));
+        }
+
+        Ok(states)
+    }
+}
+
+impl FormatGenerator for Iso8601Format {
     fn set_is_parsed(&mut self) {
-        self.parsed = true;
+        self.formats.parsed = true;
     }
 
     fn is_parsed(&self) -> bool {
-        self.parsed
+        self.formats.parsed
     }
 
     fn add(&mut self, states: StateCollection) {
-        for state in states.0 {
-            match state {
-                ApproximateState::Value(..) | ApproximateState::InPast(_) => {
-                    self.formats.push(state)
-                }
-                _ => {}
-            }
-        }
+        self.formats.add(states)
+    }
+
+    fn extend(&mut self, states: &StateCollection) {
+        self.formats.extend(states)
+    }
+
+    fn clear(&mut self) -> bool {
+        self.formats.clear();
+        true
     }
 
     fn format(&self) -> String {
         let mut in_past: Option<bool> = None;
-        let mut s = String::new();
-        let mut last = String::new();
-        for (x, format) in self.formats.iter().enumerate() {
+        // indexed in the same order as TimeBoundary::all()
+        let mut values = [0i64; 7];
+
+        for format in &self.formats {
             match format {
                 ApproximateState::InPast(past) => in_past = Some(*past),
                 ApproximateState::Value(relative, time) => {
-                    if !last.is_empty() {
-                        s += &last;
-                        last.truncate(0);
-                    }
+                    let index = TimeBoundary::all()
+                        .iter()
+                        .position(|x| x == relative)
+                        .unwrap();
+                    // states added by hand may be out of range; they saturate
+                    values[index] = values[index].saturating_add(*time);
+                }
+                _ => {}
+            }
+        }
 
-                    let relative: Words = (*relative).clone().into();
-                    let relative = if *time > 1 {
-                        relative.plural()
-                    } else {
-                        relative
-                    };
+        let [years, months, weeks, days, hours, minutes, seconds] = values;
 
-                    if self.formats.len() >= 2 && x < self.formats.len() - 2 {
-                        last = format!("{} %{{{}}}, ", time, relative);
-                    } else {
-                        last = format!("{} %{{{}}} ", time, relative);
+        let mut s = String::from("P");
+        if weeks != 0 && values.iter().filter(|x| **x != 0).count() == 1 {
+            s += &format!("{}W", weeks);
+        } else {
+            let days = weeks.saturating_mul(7).saturating_add(days);
+            for (value, designator) in [(years, 'Y'), (months, 'M'), (days, 'D')] {
+                if value != 0 {
+                    s += &format!("{}{}", value, designator);
+                }
+            }
+
+            if hours != 0 || minutes != 0 || seconds != 0 {
+                s.push('T');
+                for (value, designator) in [(hours, 'H'), (minutes, 'M'), (seconds, 'S')] {
+                    if value != 0 {
+                        s += &format!("{}{}", value, designator);
                     }
                 }
-                _ => {}
             }
+
+            if s.len() == 1 {
+                s += "T0S";
+            }
+        }
+
+        match (&self.sign, in_past) {
+            (Iso8601Sign::Negative | Iso8601Sign::Explicit, Some(true)) => format!("-{}", s),
+            (Iso8601Sign::Explicit, Some(false)) => format!("+{}", s),
+            (Iso8601Sign::Words, Some(true)) => format!("{} %{{ago}}", s),
+            (Iso8601Sign::Words, Some(false)) => format!("%{{in}} {}", s),
+            _ => s,
+        }
+    }
+}
+
+const NANOSECOND: i128 = 1;
+const MICROSECOND: i128 = 1_000 * NANOSECOND;
+const MILLISECOND: i128 = 1_000 * MICROSECOND;
+const SECOND: i128 = 1_000 * MILLISECOND;
+const MINUTE: i128 = 60 * SECOND;
+const HOUR: i128 = 60 * MINUTE;
+const DAY: i128 = 24 * HOUR;
+const WEEK: i128 = 7 * DAY;
+// systemd.time(7) defines these as averages of the Gregorian calendar.
+const SYSTEMD_MONTH: i128 = 2_629_800 * SECOND;
+const SYSTEMD_YEAR: i128 = 31_557_600 * SECOND;
+
+/// This format generates durations the way Go's `time.Duration.String()` does, like `1h2m3.5s`.
+/// Durations in the past are negative. Go has no unit larger than an hour, so days and beyond are
+/// written as hours: `48h0m0s`.
+#[derive(Clone, Default)]
+pub struct GoDurationFormat {
+    formats: DurationStates,
+}
+
+impl GoDurationFormat {
+    /// Format a [Duration] exactly as Go would, including fractional seconds: `1.5s`, `2ms`, `0s`.
+    pub fn format_duration(duration: Duration) -> String {
+        let nanos = duration
+            .num_nanoseconds()
+            .map(i128::from)
+            .unwrap_or_else(|| i128::from(duration.num_milliseconds()) * MILLISECOND);
+
+        let sign = if nanos < 0 { "-" } else { "" };
+        let nanos = nanos.abs();
+
+        if nanos == 0 {
+            return "0s".to_string();
         }
 
-        if !last.is_empty() {
-            if s.is_empty() {
-                s += last.trim();
+        if nanos < SECOND {
+            let (unit, suffix) = if nanos < MICROSECOND {
+                (NANOSECOND, "ns")
+            } else if nanos < MILLISECOND {
+                (MICROSECOND, "µs")
             } else {
-                s += &format!("%{{and}} {}", last.trim());
+                (MILLISECOND, "ms")
+            };
+
+            return format!("{}{}{}", sign, decimal(nanos, unit), suffix);
+        }
+
+        let mut s = sign.to_string();
+        if nanos >= HOUR {
+            s += &format!("{}h", nanos / HOUR);
+        }
+
+        if nanos >= MINUTE {
+            s += &format!("{}m", nanos % HOUR / MINUTE);
+        }
+
+        s + &format!("{}s", decimal(nanos % MINUTE, SECOND))
+    }
+
+    /// Parse a duration in the syntax of Go's `time.ParseDuration`, like `1h2m3.5s` or `-300ms`.
+    pub fn parse_duration(s: &str) -> Result<Duration> {
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        if rest == "0" {
+            return Ok(Duration::zero());
+        }
+
+        if rest.is_empty() {
+            return Err(anyhow!("Invalid duration '{}'", s));
+        }
+
+        let nanos = parse_units(
+            rest,
+            &[
+                ("ns", NANOSECOND),
+                ("us", MICROSECOND),
+                ("µs", MICROSECOND),
+                ("μs", MICROSECOND),
+                ("ms", MILLISECOND),
+                ("s", SECOND),
+                ("m", MINUTE),
+                ("h", HOUR),
+            ],
+            false,
+            None,
+        )?;
+
+        nanoseconds(if negative { -nanos } else { nanos })
+    }
+}
+
+impl FormatGenerator for GoDurationFormat {
+    fn set_is_parsed(&mut self) {
+        self.formats.parsed = true;
+    }
+
+    fn is_parsed(&self) -> bool {
+        self.formats.parsed
+    }
+
+    fn add(&mut self, states: StateCollection) {
+        self.formats.add(states)
+    }
+
+    fn extend(&mut self, states: &StateCollection) {
+        self.formats.extend(states)
+    }
+
+    fn clear(&mut self) -> bool {
+        self.formats.clear();
+        true
+    }
+
+    fn format(&self) -> String {
+        // states added by hand can be out of range, so write the longest duration instead
+        let duration = signed_duration(self.formats.iter()).unwrap_or_else(|_| {
+            match self.formats.contains(&ApproximateState::InPast(true)) {
+                true => Duration::min_value(),
+                false => Duration::max_value(),
             }
+        });
+
+        Self::format_duration(duration)
+    }
+}
+
+/// This format generates timespans in the syntax of systemd.time(7), like `1min 30s` or
+/// `2w 3d`. Each [ApproximateState::Value] is written with its own unit; whether the time is in
+/// the past or future is written with the `%{ago}` and `%{in}` words, as systemd has no notion of
+/// a negative timespan.
+///
+/// Note that systemd considers a month to be 30.44 days and a year 365.25 days, whereas the
+/// [Approximator](crate::approximate::Approximator) rounds with 30 and 365 days.
+#[derive(Clone, Default)]
+pub struct SystemdTimespanFormat {
+    formats: DurationStates,
+}
+
+impl SystemdTimespanFormat {
+    /// Format a [Duration] the way systemd does, including fractional seconds: `1h 30min`,
+    /// `1min 2.5s`, `500ms`. Negative durations are formatted by their absolute value.
+    pub fn format_duration(duration: Duration) -> String {
+        let nanos = duration
+            .num_nanoseconds()
+            .map(i128::from)
+            .unwrap_or_else(|| i128::from(duration.num_milliseconds()) * MILLISECOND)
+            .abs();
+
+        if nanos == 0 {
+            return "0".to_string();
         }
 
-        if let Some(past) = in_past {
-            if past {
-                s + " %{ago}"
+        if nanos < SECOND {
+            return if nanos < MILLISECOND {
+                format!("{}us", decimal(nanos, MICROSECOND))
             } else {
-                s + " %{from now}"
+                format!("{}ms", decimal(nanos, MILLISECOND))
+            };
+        }
+
+        let mut parts = Vec::new();
+        let mut remainder = nanos;
+        for (length, unit) in [
+            (SYSTEMD_YEAR, "y"),
+            (SYSTEMD_MONTH, "month"),
+            (WEEK, "w"),
+            (DAY, "d"),
+            (HOUR, "h"),
+            (MINUTE, "min"),
+        ] {
+            if remainder >= length {
+                parts.push(format!("{}{}", remainder / length, unit));
+                remainder %= length;
+            }
+        }
+
+        if remainder > 0 {
+            parts.push(format!("{}s", decimal(remainder, SECOND)));
+        }
+
+        parts.join(" ")
+    }
+
+    /// Parse a timespan in the syntax of systemd.time(7), like `1min 30s`, `2weeks 3d` or
+    /// `1.5h`. Numbers without a unit are seconds.
+    pub fn parse_duration(s: &str) -> Result<Duration> {
+        if s.trim().is_empty() {
+            return Err(anyhow!("Invalid timespan '{}'", s));
+        }
+
+        nanoseconds(parse_units(
+            s,
+            &[
+                ("nsec", NANOSECOND),
+                ("ns", NANOSECOND),
+                ("usec", MICROSECOND),
+                ("us", MICROSECOND),
+                ("µs", MICROSECOND),
+                ("msec", MILLISECOND),
+                ("ms", MILLISECOND),
+                ("seconds", SECOND),
+                ("second", SECOND),
+                ("sec", SECOND),
+                ("s", SECOND),
+                ("minutes", MINUTE),
+                ("minute", MINUTE),
+                ("min", MINUTE),
+                ("m", MINUTE),
+                ("hours", HOUR),
+                ("hour", HOUR),
+                ("hr", HOUR),
+                ("h", HOUR),
+                ("days", DAY),
+                ("day", DAY),
+                ("d", DAY),
+                ("weeks", WEEK),
+                ("week", WEEK),
+                ("w", WEEK),
+                ("months", SYSTEMD_MONTH),
+                ("month", SYSTEMD_MONTH),
+                ("M", SYSTEMD_MONTH),
+                ("years", SYSTEMD_YEAR),
+                ("year", SYSTEMD_YEAR),
+                ("y", SYSTEMD_YEAR),
+            ],
+            true,
+            Some(SECOND),
+        )?)
+    }
+}
+
+impl FormatGenerator for SystemdTimespanFormat {
+    fn set_is_parsed(&mut self) {
+        self.formats.parsed = true;
+    }
+
+    fn is_parsed(&self) -> bool {
+        self.formats.parsed
+    }
+
+    fn add(&mut self, states: StateCollection) {
+        self.formats.add(states)
+    }
+
+    fn extend(&mut self, states: &StateCollection) {
+        self.formats.extend(states)
+    }
+
+    fn clear(&mut self) -> bool {
+        self.formats.clear();
+        true
+    }
+
+    fn format(&self) -> String {
+        let mut in_past: Option<bool> = None;
+        let mut parts = Vec::new();
+
+        for format in &self.formats {
+            match format {
+                ApproximateState::InPast(past) => in_past = Some(*past),
+                ApproximateState::Value(relative, time) => {
+                    let unit = match relative {
+                        TimeBoundary::Year => "y",
+                        TimeBoundary::Month => "month",
+                        TimeBoundary::Week => "w",
+                        TimeBoundary::Day => "d",
+                        TimeBoundary::Hour => "h",
+                        TimeBoundary::Minute => "min",
+                        TimeBoundary::Second => "s",
+                    };
+
+                    parts.push(format!("{}{}", time, unit));
+                }
+                _ => {}
             }
+        }
+
+        let s = if parts.is_empty() {
+            "0".to_string()
         } else {
-            s
+            parts.join(" ")
+        };
+
+        match in_past {
+            Some(true) => format!("{} %{{ago}}", s),
+            Some(false) => format!("%{{in}} {}", s),
+            None => s,
         }
     }
 }
@@ -285,7 +1148,7 @@ mod tests {
         );
 
         let states = approximator.difference(date, date2);
-        assert_eq!("45y9m17d %{ago}", states.to_string());
+        assert_eq!("45y9mo17d %{ago}", states.to_string());
 
         let date = NaiveDateTime::new(
             NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
@@ -335,7 +1198,7 @@ mod tests {
         );
 
         let states = approximator.difference(date, date2);
-        assert_eq!("%{in} 2m", states.to_string());
+        assert_eq!("%{in} 2mo", states.to_string());
 
         let approximator = Approximator::new(
             vec![
@@ -347,6 +1210,379 @@ mod tests {
         );
 
         let states = approximator.difference(date, date2);
-        assert_eq!("%{in} 2m26d", states.to_string());
+        assert_eq!("%{in} 2mo26d", states.to_string());
+    }
+
+    #[test]
+    fn test_fancy_duration_widths() {
+        use super::*;
+        use crate::{
+            enums::{PluralCategory, Words},
+            translation_map,
+            translator::Translator,
+        };
+
+        let english = Translator::new(translation_map!(
+            (Words::Ago, "ago"),
+            (Words::In, "in"),
+            (Words::Hour, "hour"),
+            (Words::HourPlural, "hours"),
+            (Words::Minute, "minute"),
+            (Words::MinutePlural, "minutes"),
+            (
+                Words::Abbreviation(TimeBoundary::Hour, ListWidth::Short, None),
+                "hr."
+            ),
+            (
+                Words::Abbreviation(TimeBoundary::Minute, ListWidth::Short, None),
+                "min."
+            ),
+            (
+                Words::Abbreviation(TimeBoundary::Hour, ListWidth::Narrow, None),
+                "h"
+            )
+        ));
+        let japanese = Translator::new(translation_map!(
+            (Words::Language, "ja"),
+            (Words::Ago, "前"),
+            (Words::HourPlural, "時間"),
+            (Words::MinutePlural, "分"),
+            (Words::Pattern(Pattern::ShortPast), "%{duration}前"),
+            (Words::Pattern(Pattern::Quantity), "%{count}%{unit}"),
+            (
+                Words::Pattern(Pattern::List(
+                    ListStyle::new(ListKind::Unit, ListWidth::Wide),
+                    crate::enums::ListPart::Two
+                )),
+                "%{0}%{1}"
+            ),
+            (
+                Words::Abbreviation(TimeBoundary::Hour, ListWidth::Short, None),
+                "時間"
+            ),
+            (
+                Words::Abbreviation(TimeBoundary::Minute, ListWidth::Short, None),
+                "分"
+            ),
+            (
+                Words::Abbreviation(
+                    TimeBoundary::Minute,
+                    ListWidth::Short,
+                    Some(PluralCategory::Other)
+                ),
+                "分"
+            )
+        ));
+
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::InPast(true));
+        states.push(ApproximateState::Value(TimeBoundary::Hour, 2));
+        states.push(ApproximateState::Value(TimeBoundary::Minute, 1));
+
+        let cases = [
+            (ListWidth::Wide, None, "2 hours, 1 minute ago", "2時間1分前"),
+            (ListWidth::Short, None, "2 hr., 1 min. ago", "2時間1分前"),
+            // minutes have no narrow form, and fall back to short
+            (ListWidth::Narrow, None, "2h 1min. ago", "2時間1分前"),
+            (ListWidth::Narrow, Some("+"), "2h+1min. ago", "2時間+1分前"),
+        ];
+
+        for (width, separator, expected, expected_japanese) in cases {
+            let mut format = FancyDurationFormat::default().with_unit_width(width);
+            if let Some(separator) = separator {
+                format = format.with_separator(separator);
+            }
+
+            format.add(states.clone());
+            let template = format.template();
+            assert_eq!(Ok(expected.to_string()), english.render_checked(&template));
+            assert_eq!(expected_japanese, japanese.render(&template));
+        }
+
+        let mut format = FancyDurationFormat::default().with_unit_width(ListWidth::Short);
+        format.add(states);
+        assert_eq!("2 %{hour_short}, 1 %{minute_short} %{ago}", format.format());
+    }
+
+    #[test]
+    fn test_fancy_duration_parse() {
+        use super::*;
+
+        let format = FancyDurationFormat::default();
+        let states = format.parse("2y1mo15m3s").unwrap();
+        assert_eq!(
+            states.iter().cloned().collect::<Vec<_>>(),
+            vec![
+                ApproximateState::Value(TimeBoundary::Year, 2),
+                ApproximateState::Value(TimeBoundary::Month, 1),
+                ApproximateState::Value(TimeBoundary::Minute, 15),
+                ApproximateState::Value(TimeBoundary::Second, 3),
+            ]
+        );
+
+        assert_eq!(
+            Duration::days(2) + Duration::hours(4),
+            format.parse_duration("%{in} 2d4h").unwrap()
+        );
+        assert_eq!(
+            -Duration::days(30),
+            format.parse_duration("1mo %{ago}").unwrap()
+        );
+
+        assert!(format.parse("").is_err());
+        assert!(format.parse("y").is_err());
+        assert!(format.parse("2").is_err());
+        assert!(format.parse("2x").is_err());
+        assert!(format.parse_duration("9999999999999999y").is_err());
+        assert!(format.parse_duration("200000000y10000000000w").is_err());
+        assert!(FancyDurationFormat::default()
+            .with_month_marker("m")
+            .parse("2m")
+            .is_err());
+    }
+
+    #[test]
+    fn test_fancy_duration_round_trip() {
+        use super::*;
+        use proptest::test_runner::TestRunner;
+        use proptest::{collection, option, prelude::*, test_runner::Config};
+
+        let all = TimeBoundary::all();
+
+        // every combination of units, each with generated values and direction
+        for mask in 1..(1 << all.len()) {
+            let units: Vec<TimeBoundary> = all
+                .iter()
+                .enumerate()
+                .filter(|(x, _)| mask & (1 << x) != 0)
+                .map(|(_, relative)| relative.clone())
+                .collect();
+
+            let strategy = (
+                option::of(any::<bool>()),
+                collection::vec(1..=100_000_000i64, units.len()),
+            );
+            let mut runner = TestRunner::new(Config::with_cases(16));
+            let result = runner.run(&strategy, |(in_past, values)| {
+                let mut states = StateCollection::default();
+                if let Some(past) = in_past {
+                    states.push(ApproximateState::InPast(past));
+                }
+
+                for (relative, time) in units.iter().zip(&values) {
+                    states.push(ApproximateState::Value(relative.clone(), *time));
+                }
+
+                let mut format = FancyDurationFormat::default();
+                format.add(states.clone());
+                let parsed = format.parse(&format.format()).unwrap();
+                prop_assert_eq!(&states, &parsed, "{}", format.format());
+
+                let duration = units
+                    .iter()
+                    .zip(&values)
+                    .fold(Duration::zero(), |acc, (relative, time)| {
+                        acc + relative.duration(*time).unwrap()
+                    });
+
+                prop_assert_eq!(
+                    if in_past == Some(true) {
+                        -duration
+                    } else {
+                        duration
+                    },
+                    format.parse_duration(&format.format()).unwrap()
+                );
+                Ok(())
+            });
+
+            if let Err(e) = result {
+                panic!("{:?}: {}", units, e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_iso8601_format() {
+        use super::*;
+
+        let mut states = StateCollection::default();
+        for state in [
+            ApproximateState::InPast(true),
+            ApproximateState::Value(TimeBoundary::Year, 1),
+            ApproximateState::Value(TimeBoundary::Month, 2),
+            ApproximateState::Value(TimeBoundary::Day, 3),
+            ApproximateState::Value(TimeBoundary::Hour, 4),
+            ApproximateState::Value(TimeBoundary::Minute, 5),
+            ApproximateState::Value(TimeBoundary::Second, 6),
+        ] {
+            states.push(state);
+        }
+
+        let mut format = Iso8601Format::default();
+        format.add(states.clone());
+        assert_eq!("-P1Y2M3DT4H5M6S", format.format());
+        assert_eq!(states, format.parse(&format.format()).unwrap());
+
+        let mut format = Iso8601Format::default().with_sign(Iso8601Sign::Words);
+        format.add(states.clone());
+        assert_eq!("P1Y2M3DT4H5M6S %{ago}", format.format());
+        assert_eq!(states, format.parse(&format.format()).unwrap());
+
+        let mut format = Iso8601Format::default().with_sign(Iso8601Sign::Explicit);
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::InPast(false));
+        states.push(ApproximateState::Value(TimeBoundary::Week, 2));
+        format.add(states.clone());
+        assert_eq!("+P2W", format.format());
+        assert_eq!(states, format.parse(&format.format()).unwrap());
+
+        let mut format = Iso8601Format::default();
+        states.push(ApproximateState::Value(TimeBoundary::Hour, 1));
+        format.add(states);
+        assert_eq!("P14DT1H", format.format());
+        assert_eq!("PT0S", Iso8601Format::default().format());
+
+        let format = Iso8601Format::default();
+        let states = format.parse("PT90M").unwrap();
+        assert!(states.contains(&ApproximateState::Value(TimeBoundary::Minute, 90)));
+
+        let mut coarse = CoarseRoundFormat::default();
+        coarse.add(format.parse("-P2M3D").unwrap());
+        assert_eq!("2 %{months} %{and} 3 %{days} %{ago}", coarse.format());
+
+        for bad in [
+            "",
+            "P",
+            "PT",
+            "1D",
+            "P1",
+            "PD",
+            "P1D1Y",
+            "PT1.5S",
+            "P1H",
+            "PT1D",
+            "P9223372036854775807W1D",
+            "P99999999999999Y",
+        ] {
+            assert!(format.parse(bad).is_err(), "{}", bad);
+        }
+
+        // states added by hand are not checked, but still format
+        let mut format = Iso8601Format::default();
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::Value(TimeBoundary::Week, i64::MAX));
+        states.push(ApproximateState::Value(TimeBoundary::Day, 1));
+        states.push(ApproximateState::Value(TimeBoundary::Day, i64::MAX));
+        format.add(states);
+        assert_eq!(format!("P{}D", i64::MAX), format.format());
+    }
+
+    #[test]
+    fn test_go_duration_format() {
+        use super::*;
+
+        for (duration, s) in [
+            (Duration::zero(), "0s"),
+            (Duration::nanoseconds(3), "3ns"),
+            (Duration::microseconds(1500), "1.5ms"),
+            (Duration::microseconds(500), "500µs"),
+            (Duration::milliseconds(3500), "3.5s"),
+            (Duration::seconds(90), "1m30s"),
+            (Duration::hours(1), "1h0m0s"),
+            (
+                Duration::seconds(3723) + Duration::milliseconds(500),
+                "1h2m3.5s",
+            ),
+            (Duration::days(2), "48h0m0s"),
+            (-Duration::minutes(5), "-5m0s"),
+        ] {
+            assert_eq!(s, GoDurationFormat::format_duration(duration));
+            assert_eq!(duration, GoDurationFormat::parse_duration(s).unwrap());
+        }
+
+        assert_eq!(
+            Duration::milliseconds(1500),
+            GoDurationFormat::parse_duration("1.5s").unwrap()
+        );
+        assert_eq!(
+            Duration::minutes(90),
+            GoDurationFormat::parse_duration("1.5h").unwrap()
+        );
+        assert_eq!(
+            Duration::zero(),
+            GoDurationFormat::parse_duration("0").unwrap()
+        );
+        for bad in [
+            "",
+            "-",
+            "1",
+            "1d",
+            "1 h",
+            ".s",
+            "h",
+            "99999999999999999999999999999h",
+            "40000000000000000000000000h40000000000000000000000000h",
+        ] {
+            assert!(GoDurationFormat::parse_duration(bad).is_err(), "{}", bad);
+        }
+
+        let mut format = GoDurationFormat::default();
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::InPast(true));
+        states.push(ApproximateState::Value(TimeBoundary::Day, 1));
+        states.push(ApproximateState::Value(TimeBoundary::Minute, 2));
+        format.add(states);
+        assert_eq!("-24h2m0s", format.format());
+    }
+
+    #[test]
+    fn test_systemd_timespan_format() {
+        use super::*;
+
+        for (duration, s) in [
+            (Duration::seconds(90), "1min 30s"),
+            (Duration::weeks(2) + Duration::days(3), "2w 3d"),
+            (Duration::milliseconds(62500), "1min 2.5s"),
+            (Duration::milliseconds(500), "500ms"),
+            (Duration::microseconds(20), "20us"),
+            (Duration::seconds(31_557_600 + 3600), "1y 1h"),
+        ] {
+            assert_eq!(s, SystemdTimespanFormat::format_duration(duration));
+            assert_eq!(duration, SystemdTimespanFormat::parse_duration(s).unwrap());
+        }
+
+        for (s, duration) in [
+            ("2weeks 3d", Duration::days(17)),
+            ("1h30min", Duration::minutes(90)),
+            ("5 min", Duration::minutes(5)),
+            ("1.5h", Duration::minutes(90)),
+            ("30", Duration::seconds(30)),
+            ("1month", Duration::seconds(2_629_800)),
+        ] {
+            assert_eq!(duration, SystemdTimespanFormat::parse_duration(s).unwrap());
+        }
+
+        for bad in [
+            "",
+            "1 parsec",
+            "min",
+            "99999999999999999999999999999h",
+            "40000000000000000000000000h40000000000000000000000000h",
+        ] {
+            assert!(
+                SystemdTimespanFormat::parse_duration(bad).is_err(),
+                "{}",
+                bad
+            );
+        }
+
+        let mut format = SystemdTimespanFormat::default();
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::InPast(false));
+        states.push(ApproximateState::Value(TimeBoundary::Minute, 1));
+        states.push(ApproximateState::Value(TimeBoundary::Second, 30));
+        format.add(states);
+        assert_eq!("%{in} 1min 30s", format.format());
     }
 }
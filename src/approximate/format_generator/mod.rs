@@ -1,17 +1,58 @@
 pub mod formats;
 
-pub use self::formats::{CoarseRoundFormat, FancyDurationFormat};
+pub use self::formats::{
+    CoarseRoundFormat, FancyDurationFormat, GoDurationFormat, Iso8601Format, Iso8601Sign,
+    SystemdTimespanFormat,
+};
 use super::StateCollection;
+use crate::translator::Template;
 
 /// Implement this trait to generate a format. This consumes
 /// [ApproximateState](crate::approximate::ApproximateState) and produces a string format,
 /// ultimately. It sets a parsed state when the format is considered "baked", making it easy for
 /// formatters to repeat the same value without recalculating.
+///
+/// Formats may also be produced as a compiled [Template], which the
+/// [Translator](crate::translator::Translator) can render without parsing; override
+/// [FormatGenerator::template_into] to build one directly.
+///
+/// Generators are re-used when rendering in bulk (see
+/// [Batch](crate::approximate::Batch)): override [FormatGenerator::clear] to reset them without
+/// allocating, and [FormatGenerator::extend] to avoid the clone its default implementation makes.
 pub trait FormatGenerator {
     fn set_is_parsed(&mut self);
     fn is_parsed(&self) -> bool;
     fn add(&mut self, state: StateCollection);
     fn format(&self) -> String;
+    /// Forget any states that were added, and the parsed state, so the generator can be re-used.
+    /// Returns whether it did; by default nothing is forgotten, and a
+    /// [Batch](crate::approximate::Batch) clones a new generator for each time instead.
+    fn clear(&mut self) -> bool {
+        false
+    }
+
+    /// Add states from a borrowed collection. By default this clones the collection and calls
+    /// [FormatGenerator::add].
+    fn extend(&mut self, state: &StateCollection) {
+        self.add(state.clone())
+    }
+
+    /// Append the format to a [Template]. By default this parses the result of
+    /// [FormatGenerator::format]; if that is not a valid format, it is appended as literal text.
+    fn template_into(&self, template: &mut Template) {
+        let format = self.format();
+        match format.parse() {
+            Ok(parsed) => template.append(parsed),
+            Err(_) => template.literal(format),
+        }
+    }
+
+    /// The format as a compiled [Template].
+    fn template(&self) -> Template {
+        let mut template = Template::default();
+        self.template_into(&mut template);
+        template
+    }
 }
 
 /// Used for testing mostly, always yields an empty string, is always parsed.
@@ -27,4 +68,7 @@ impl FormatGenerator for EmptyFormatGenerator {
     fn format(&self) -> String {
         String::new()
     }
+    fn clear(&mut self) -> bool {
+        true
+    }
 }
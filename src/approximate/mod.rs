@@ -2,11 +2,18 @@
 /// strings which can then be run through the [Translator](crate::translator::Translator).
 pub mod format_generator;
 
-pub use self::format_generator::formats::{CoarseRoundFormat, FancyDurationFormat};
+pub use self::format_generator::formats::{
+    CoarseRoundFormat, FancyDurationFormat, GoDurationFormat, Iso8601Format, Iso8601Sign,
+    SystemdTimespanFormat,
+};
 
 use self::format_generator::FormatGenerator;
 use super::enums::{Month, Weekday};
-use crate::time_boundary::TimeBoundary;
+use crate::{
+    time_boundary::TimeBoundary,
+    translator::{Template, Translate},
+};
+use anyhow::Result;
 use chrono::prelude::*;
 use chrono::Duration;
 
@@ -75,7 +82,7 @@ pub enum ApproximateState {
 
 /// This is a container for [ApproximateState] values. These are returned in this shell so that
 /// they can be checked when generating a grammar.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct StateCollection(Vec<ApproximateState>);
 
 impl StateCollection {
@@ -90,6 +97,12 @@ impl StateCollection {
     pub fn push(&mut self, key: ApproximateState) {
         self.0.push(key)
     }
+
+    /// Iterate over the states in the order they were added.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, ApproximateState> {
+        self.0.iter()
+    }
 }
 
 /// The state formatter drives the [FormatGenerator]
@@ -117,7 +130,7 @@ where
     /// its internal parser.
     #[inline]
     pub fn parse(&mut self) {
-        self.obj.add(self.states.clone());
+        self.obj.extend(&self.states);
         self.obj.set_is_parsed();
     }
 
@@ -137,8 +150,30 @@ where
         if self.obj.is_parsed() {
             self.obj.format()
         } else {
-            self.clone().format()
+            let mut obj = self.obj.clone();
+            obj.extend(&self.states);
+            obj.format()
+        }
+    }
+
+    /// Compile the format into a [Template], parsing the collection first if needed.
+    pub fn template(&self) -> Template {
+        if self.obj.is_parsed() {
+            self.obj.template()
+        } else {
+            let mut obj = self.obj.clone();
+            obj.extend(&self.states);
+            obj.template()
+        }
+    }
+
+    /// Like [StateFormatter::template], but consumes the formatter so that its
+    /// [FormatGenerator] need not be cloned.
+    pub fn into_template(mut self) -> Template {
+        if !self.obj.is_parsed() {
+            self.parse();
         }
+        self.obj.template()
     }
 
     /// Return the [StateCollection] being held by this formatter.
@@ -188,41 +223,130 @@ where
 
     /// Compute the difference of two times. The first time is considered the "original", and the
     /// second the "compared" values when generating results in [ApproximateState] results.
+    ///
+    /// The [StateFormatter] owns a copy of the [FormatGenerator], which holds no states yet, so
+    /// only its settings are cloned. To render many times without even that, use
+    /// [Approximator::batch].
     pub fn difference(&self, dt: DateTime<Local>, against: DateTime<Local>) -> StateFormatter<T> {
-        let mut state = StateCollection::default();
-
-        state.push(ApproximateState::WithDate(dt.date_naive()));
-        state.push(ApproximateState::WithTime(dt.time()));
-        let duration = dt - against;
-        state = self.for_duration(Some(state.clone()), duration);
-        state = self.for_time_and_duration(Some(state.clone()), dt, duration);
         StateFormatter {
-            states: state,
+            states: self.states(dt, against),
             obj: self.obj.clone(),
         }
     }
 
+    /// Compute the next instant after `against` at which [Approximator::difference] would
+    /// produce a different [StateCollection] for `dt`, if the "compared" time kept moving forward
+    /// from `against` like a clock. This is useful for live displays such as "3 minutes ago",
+    /// which only need to be re-rendered at the returned instant instead of every second. [None]
+    /// is returned if the states will not change in the foreseeable future (roughly a century).
+    ///
+    /// The result is exact to the nanosecond, provided the states do not repeat once they have
+    /// changed while the difference only grows, or only shrinks, which is the case for all of the
+    /// [ApproximateFilter]s. Without [ApproximateFilter::Relative], the states either side of `dt`
+    /// can be the same, such as "5 minutes" before and after it, so the time before `dt` is
+    /// searched on its own and a change in between is not skipped.
+    pub fn refresh_at(
+        &self,
+        dt: DateTime<Local>,
+        against: DateTime<Local>,
+    ) -> Option<DateTime<Local>> {
+        let current = self.states(dt, against);
+        let changed =
+            |offset: i64| self.states(dt, against + Duration::nanoseconds(offset)) != current;
+        let crossing = (dt - against).num_nanoseconds().filter(|x| *x > 0);
+
+        // gallop forward until the states change, then narrow down the first instant they do.
+        // the gallop stops at `dt` on the way, so each search stays on one side of it.
+        let mut low = 0;
+        let mut high = 1;
+        while !changed(high) {
+            low = high;
+            high = high.checked_mul(2).filter(|x| *x < 1 << 62)?;
+            if let Some(crossing) = crossing.filter(|x| low < *x && *x < high) {
+                high = crossing;
+            }
+        }
+
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if changed(mid) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        Some(against + Duration::nanoseconds(high))
+    }
+
+    /// Start rendering many times against the same anchor, such as the rows of a table. The
+    /// anchor is used as the "compared" time for every row, so the results are consistent even if
+    /// the clock ticks part-way through, and the [Batch] re-uses its buffers between rows.
+    pub fn batch(&self, anchor: DateTime<Local>) -> Batch<'_, T> {
+        let mut obj = self.obj.clone();
+        if !obj.clear() {
+            obj = self.obj.clone();
+        }
+
+        Batch {
+            approx: self,
+            anchor,
+            obj,
+            states: StateCollection::default(),
+            template: Template::default(),
+            output: String::new(),
+        }
+    }
+
+    /// Build a [Ticker] that yields a new [StateFormatter] for `dt` against the current time each
+    /// time its output changes, sleeping in between. See [Approximator::refresh_at].
+    pub fn ticker(&self, dt: DateTime<Local>) -> Ticker<'_, T> {
+        Ticker {
+            approx: self,
+            dt,
+            clock: SystemClock,
+            last: None,
+        }
+    }
+
     /// Compute the tokens just understood for Durations. This is a limited subset of
     /// [Approximator::difference] and [Approximator::from_now].
     pub fn duration(&self, duration: chrono::Duration) -> StateFormatter<T> {
         StateFormatter {
-            states: self.for_duration(None, duration),
+            states: {
+                let mut state = StateCollection::default();
+                self.for_duration(&mut state, duration);
+                state
+            },
             obj: self.obj.clone(),
         }
     }
 
+    fn states(&self, dt: DateTime<Local>, against: DateTime<Local>) -> StateCollection {
+        let mut state = StateCollection::default();
+        self.fill_states(&mut state, dt, against);
+        state
+    }
+
+    fn fill_states(
+        &self,
+        state: &mut StateCollection,
+        dt: DateTime<Local>,
+        against: DateTime<Local>,
+    ) {
+        state.push(ApproximateState::WithDate(dt.date_naive()));
+        state.push(ApproximateState::WithTime(dt.time()));
+        let duration = dt - against;
+        self.for_duration(state, duration);
+        self.for_time_and_duration(state, dt, duration);
+    }
+
     fn for_time_and_duration(
         &self,
-        state: Option<StateCollection>,
+        state: &mut StateCollection,
         dt: DateTime<Local>,
         duration: Duration,
-    ) -> StateCollection {
-        let mut state = if let Some(state) = state {
-            state
-        } else {
-            StateCollection::default()
-        };
-
+    ) {
         let duration = duration.abs();
 
         for item in &self.filter {
@@ -255,36 +379,20 @@ where
                         state.push(ApproximateState::HourShorthand(hour.to_string()));
                     }
                 }
-                ApproximateFilter::MonthNameWithinYear => {
-                    if duration.num_days() < 365 {
-                        let name: Month = dt.month0().into();
-                        state.push(ApproximateState::MonthName(name));
-                    }
+                ApproximateFilter::MonthNameWithinYear if duration.num_days() < 365 => {
+                    let name: Month = dt.month0().into();
+                    state.push(ApproximateState::MonthName(name));
                 }
-                ApproximateFilter::DayNameWithinWeek => {
-                    if duration.num_days() < 7 {
-                        let name: Weekday = dt.weekday().into();
-                        state.push(ApproximateState::DayName(name));
-                    }
+                ApproximateFilter::DayNameWithinWeek if duration.num_days() < 7 => {
+                    let name: Weekday = dt.weekday().into();
+                    state.push(ApproximateState::DayName(name));
                 }
                 _ => {}
             }
         }
-
-        state
     }
 
-    fn for_duration(
-        &self,
-        state: Option<StateCollection>,
-        duration: chrono::Duration,
-    ) -> StateCollection {
-        let mut state = if let Some(state) = state {
-            state
-        } else {
-            StateCollection::default()
-        };
-
+    fn for_duration(&self, state: &mut StateCollection, duration: chrono::Duration) {
         let orig_duration = duration;
         let mut duration = duration.abs();
 
@@ -343,18 +451,188 @@ where
                 _ => {}
             }
         }
+    }
+}
 
-        state
+/// Renders many times against a single anchor, re-using the [StateCollection], the
+/// [FormatGenerator] and the string buffers between each one. Build one with
+/// [Approximator::batch].
+pub struct Batch<'a, T>
+where
+    T: FormatGenerator + Clone,
+{
+    approx: &'a Approximator<T>,
+    anchor: DateTime<Local>,
+    obj: T,
+    states: StateCollection,
+    template: Template,
+    output: String,
+}
+
+impl<T> Batch<'_, T>
+where
+    T: FormatGenerator + Clone,
+{
+    /// The time every row is compared against.
+    #[inline]
+    pub fn anchor(&self) -> DateTime<Local> {
+        self.anchor
+    }
+
+    /// Compile the format for `dt`. The template is overwritten by the next call.
+    pub fn template(&mut self, dt: DateTime<Local>) -> &Template {
+        self.states.0.clear();
+        self.approx.fill_states(&mut self.states, dt, self.anchor);
+        if !self.obj.clear() {
+            self.obj = self.approx.obj.clone();
+        }
+        self.obj.extend(&self.states);
+        self.obj.set_is_parsed();
+        self.template.clear();
+        self.obj.template_into(&mut self.template);
+        &self.template
+    }
+
+    /// Translate the format for `dt` into `w`.
+    pub fn write<W: std::fmt::Write>(
+        &mut self,
+        dt: DateTime<Local>,
+        translator: &dyn Translate,
+        w: &mut W,
+    ) -> Result<()> {
+        self.template(dt);
+        Ok(translator.render_into(&self.template, w)?)
+    }
+
+    /// Translate the format for `dt` into a buffer owned by the [Batch], which is overwritten by
+    /// the next call.
+    pub fn render(&mut self, dt: DateTime<Local>, translator: &dyn Translate) -> Result<&str> {
+        let mut output = std::mem::take(&mut self.output);
+        output.clear();
+        let result = self.write(dt, translator, &mut output);
+        self.output = output;
+        result?;
+
+        Ok(&self.output)
+    }
+
+    /// Translate each of the times into `w`, writing `separator` between them.
+    pub fn write_all<I, W>(
+        &mut self,
+        times: I,
+        translator: &dyn Translate,
+        separator: &str,
+        w: &mut W,
+    ) -> Result<()>
+    where
+        I: IntoIterator<Item = DateTime<Local>>,
+        W: std::fmt::Write,
+    {
+        for (x, dt) in times.into_iter().enumerate() {
+            if x > 0 {
+                w.write_str(separator)?;
+            }
+
+            self.write(dt, translator, w)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The source of the current time for a [Ticker], and the way it waits for the next change.
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> DateTime<Local>;
+
+    /// Block for the duration.
+    fn sleep(&self, duration: std::time::Duration);
+}
+
+/// The [Clock] of the system, which sleeps the current thread.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+
+    #[inline]
+    fn sleep(&self, duration: std::time::Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+/// An iterator which follows a time against the clock, yielding a [StateFormatter] only when the
+/// formatted output changes. Calls to [Iterator::next] block until that happens; the iterator ends
+/// when the output will no longer change. Build one with [Approximator::ticker].
+pub struct Ticker<'a, T, C = SystemClock>
+where
+    T: FormatGenerator + Clone,
+    C: Clock,
+{
+    approx: &'a Approximator<T>,
+    dt: DateTime<Local>,
+    clock: C,
+    last: Option<String>,
+}
+
+impl<'a, T, C> Ticker<'a, T, C>
+where
+    T: FormatGenerator + Clone,
+    C: Clock,
+{
+    /// Follow another [Clock] than the system's, such as a simulated one.
+    pub fn with_clock<D: Clock>(self, clock: D) -> Ticker<'a, T, D> {
+        Ticker {
+            approx: self.approx,
+            dt: self.dt,
+            clock,
+            last: self.last,
+        }
+    }
+}
+
+impl<T, C> Iterator for Ticker<'_, T, C>
+where
+    T: FormatGenerator + Clone,
+    C: Clock,
+{
+    type Item = StateFormatter<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.last.is_some() {
+                let now = self.clock.now();
+                let at = self.approx.refresh_at(self.dt, now)?;
+                if let Ok(wait) = (at - now).to_std() {
+                    self.clock.sleep(wait);
+                }
+            }
+
+            let formatter = self.approx.difference(self.dt, self.clock.now());
+            let s = formatter.to_string();
+            if self.last.as_ref() != Some(&s) {
+                self.last = Some(s);
+                return Some(formatter);
+            }
+        }
     }
 }
 
+/// A pair of functions: one to count the boundaries in a duration, the other to turn a count back
+/// into a duration.
+type BoundaryConversion<'a> = (&'a dyn Fn(&Duration) -> i64, &'a dyn Fn(i64) -> Duration);
+
 /// convenience function to convert time ranges based on their boundaries.
 fn match_relative(
     duration: &mut Duration,
     relative: TimeBoundary,
     upto: Option<i64>,
 ) -> Option<ApproximateState> {
-    let result: (&dyn Fn(&Duration) -> i64, &dyn Fn(i64) -> Duration) = match relative {
+    let result: BoundaryConversion = match relative {
         TimeBoundary::Year => (&|dur: &Duration| dur.num_days() / 365, &|count| {
             Duration::seconds(count * Duration::days(365).num_seconds())
         }),
@@ -590,4 +868,193 @@ mod tests {
             assert!(states.contains(state))
         }
     }
+
+    #[test]
+    fn test_refresh_at() {
+        use super::format_generator::EmptyFormatGenerator;
+        use super::*;
+
+        let date = NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+            NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+        )
+        .and_local_timezone(chrono::Local)
+        .unwrap();
+
+        let approximator = Approximator::new(
+            vec![ApproximateFilter::TopRounds(1), ApproximateFilter::Relative],
+            EmptyFormatGenerator,
+        );
+
+        // "3 minutes ago" becomes "4 minutes ago" on the minute.
+        let now = date + Duration::seconds(210) + Duration::milliseconds(250);
+        assert_eq!(
+            Some(date + Duration::minutes(4)),
+            approximator.refresh_at(date, now)
+        );
+
+        // "in 2 days" becomes "in 1 day" just after two days remain.
+        let now = date - Duration::days(2) - Duration::seconds(1);
+        assert_eq!(
+            Some(now + Duration::seconds(1) + Duration::nanoseconds(1)),
+            approximator.refresh_at(date, now)
+        );
+
+        let approximator = Approximator::new(
+            vec![ApproximateFilter::Round(TimeBoundary::Year)],
+            EmptyFormatGenerator,
+        );
+        assert_eq!(
+            Some(date + Duration::days(365)),
+            approximator.refresh_at(date, date + Duration::days(30))
+        );
+
+        // without Relative, the states a month before and after are the same, but the weekday
+        // is named in between.
+        let approximator = Approximator::new(
+            vec![ApproximateFilter::DayNameWithinWeek],
+            EmptyFormatGenerator,
+        );
+        assert_eq!(
+            Some(date - Duration::days(7) + Duration::nanoseconds(1)),
+            approximator.refresh_at(date, date - Duration::days(30))
+        );
+        assert_eq!(
+            Some(date + Duration::days(7)),
+            approximator.refresh_at(date, date - Duration::days(6))
+        );
+
+        let approximator = Approximator::new(vec![], EmptyFormatGenerator);
+        assert_eq!(None, approximator.refresh_at(date, date));
+    }
+
+    #[test]
+    fn test_ticker() {
+        use super::*;
+        use std::cell::Cell;
+
+        /// A clock which only moves when slept on.
+        struct Simulated<'a> {
+            now: &'a Cell<DateTime<Local>>,
+            sleeps: &'a Cell<usize>,
+        }
+
+        impl Clock for Simulated<'_> {
+            fn now(&self) -> DateTime<Local> {
+                self.now.get()
+            }
+
+            fn sleep(&self, duration: std::time::Duration) {
+                self.sleeps.set(self.sleeps.get() + 1);
+                self.now
+                    .set(self.now.get() + Duration::from_std(duration).unwrap());
+            }
+        }
+
+        let date = NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+            NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+        )
+        .and_local_timezone(chrono::Local)
+        .unwrap();
+
+        let approximator = Approximator::new(
+            vec![ApproximateFilter::TopRounds(1), ApproximateFilter::Relative],
+            CoarseRoundFormat::default(),
+        );
+
+        let now = Cell::new(date + Duration::seconds(59) + Duration::milliseconds(500));
+        let sleeps = Cell::new(0);
+        let mut ticker = approximator.ticker(date).with_clock(Simulated {
+            now: &now,
+            sleeps: &sleeps,
+        });
+
+        let mut next = || ticker.next().map(|formatter| formatter.to_string());
+        assert_eq!(Some("59 %{seconds} %{ago}".to_string()), next());
+        assert_eq!(0, sleeps.get());
+        assert_eq!(Some("1 %{minute} %{ago}".to_string()), next());
+        assert_eq!(date + Duration::minutes(1), now.get());
+        assert_eq!(Some("2 %{minutes} %{ago}".to_string()), next());
+        assert_eq!(date + Duration::minutes(2), now.get());
+        assert_eq!(2, sleeps.get());
+    }
+
+    #[test]
+    fn test_batch() {
+        use super::*;
+        use crate::translator::DEFAULT_TRANSLATION;
+
+        let anchor = NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+            NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+        )
+        .and_local_timezone(chrono::Local)
+        .unwrap();
+
+        let approximator = Approximator::new(
+            vec![ApproximateFilter::TopRounds(2), ApproximateFilter::Relative],
+            CoarseRoundFormat::default(),
+        );
+
+        let times = [
+            anchor - Duration::days(2),
+            anchor + Duration::hours(3) + Duration::minutes(5),
+            anchor - Duration::seconds(30),
+        ];
+
+        let mut batch = approximator.batch(anchor);
+        for dt in times {
+            assert_eq!(
+                DEFAULT_TRANSLATION
+                    .format(&approximator.difference(dt, anchor).to_string())
+                    .unwrap(),
+                batch.render(dt, &DEFAULT_TRANSLATION).unwrap()
+            );
+        }
+
+        let mut s = String::new();
+        batch
+            .write_all(times, &DEFAULT_TRANSLATION, "\n", &mut s)
+            .unwrap();
+        assert_eq!(
+            "2 days ago\n3 hours and 5 minutes from now\n30 seconds ago",
+            s
+        );
+
+        /// Writes the values it was given, and cannot clear them.
+        #[derive(Clone, Default)]
+        struct Values(Vec<i64>, bool);
+
+        impl FormatGenerator for Values {
+            fn set_is_parsed(&mut self) {
+                self.1 = true;
+            }
+
+            fn is_parsed(&self) -> bool {
+                self.1
+            }
+
+            fn add(&mut self, states: StateCollection) {
+                for state in states.iter() {
+                    if let ApproximateState::Value(_, value) = state {
+                        self.0.push(*value);
+                    }
+                }
+            }
+
+            fn format(&self) -> String {
+                format!("{:?}", self.0)
+            }
+        }
+
+        let approximator =
+            Approximator::new(vec![ApproximateFilter::TopRounds(2)], Values::default());
+        let mut batch = approximator.batch(anchor);
+        let mut s = String::new();
+        batch
+            .write_all(times, &DEFAULT_TRANSLATION, " ", &mut s)
+            .unwrap();
+        assert_eq!("[2] [3, 5] [30]", s);
+    }
 }
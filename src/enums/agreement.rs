@@ -0,0 +1,82 @@
+use crate::enums::Words;
+
+/// The grammatical gender of a noun, which modifiers such as "last" agree with. Locales declare
+/// the gender of each unit and weekday with keys like `week_gender: feminine`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+    /// The merged masculine and feminine gender of Swedish, Danish and Dutch.
+    Common,
+}
+
+impl Gender {
+    /// Yield all genders.
+    pub fn all() -> [Self; 4] {
+        [Self::Masculine, Self::Feminine, Self::Neuter, Self::Common]
+    }
+}
+
+impl std::fmt::Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Masculine => "masculine",
+            Self::Feminine => "feminine",
+            Self::Neuter => "neuter",
+            Self::Common => "common",
+        })
+    }
+}
+
+impl std::str::FromStr for Gender {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "masculine" => Self::Masculine,
+            "feminine" => Self::Feminine,
+            "neuter" => Self::Neuter,
+            "common" => Self::Common,
+            x => return Err(anyhow::anyhow!("invalid gender '{}'", x)),
+        })
+    }
+}
+
+/// Words which modify a noun and agree with its [Gender], as in "la semaine dernière" and "le
+/// mois dernier". Each translates to a plain word, with optional gendered forms such as
+/// `last_feminine`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Modifier {
+    Last,
+    Next,
+    This,
+}
+
+impl Modifier {
+    /// Yield all modifiers.
+    pub fn all() -> [Self; 3] {
+        [Self::Last, Self::Next, Self::This]
+    }
+}
+
+impl From<Modifier> for Words {
+    fn from(value: Modifier) -> Self {
+        match value {
+            Modifier::Last => Words::Last,
+            Modifier::Next => Words::Next,
+            Modifier::This => Words::This,
+        }
+    }
+}
+
+impl From<Words> for Option<Modifier> {
+    fn from(value: Words) -> Self {
+        match value {
+            Words::Last => Some(Modifier::Last),
+            Words::Next => Some(Modifier::Next),
+            Words::This => Some(Modifier::This),
+            _ => None,
+        }
+    }
+}
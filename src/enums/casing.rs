@@ -0,0 +1,157 @@
+use super::Words;
+
+/// Where translated output is placed, which decides whether its first letter is capitalized, as
+/// CLDR context transforms do. Locale words are written in lowercase, as they appear in the middle
+/// of a sentence, and capitalized when rendering; see
+/// [Translator::with_capitalization](crate::translator::Translator::with_capitalization).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Capitalization {
+    /// Inside running text, such as "it was yesterday". Nothing is capitalized beyond the words
+    /// the [CasingRules] always capitalize. This is the default.
+    #[default]
+    MiddleOfSentence,
+    /// At the start of a sentence: "Yesterday at noon".
+    BeginningOfSentence,
+    /// On its own, such as a label or a tooltip: "In 2 days".
+    Standalone,
+    /// As an item in a menu or list in a user interface: "Last week".
+    UiListItem,
+}
+
+impl Capitalization {
+    /// Yield all contexts.
+    pub fn all() -> [Self; 4] {
+        [
+            Self::MiddleOfSentence,
+            Self::BeginningOfSentence,
+            Self::Standalone,
+            Self::UiListItem,
+        ]
+    }
+
+    /// Is the first letter of output in this context capitalized?
+    pub fn capitalizes_first(&self) -> bool {
+        *self != Self::MiddleOfSentence
+    }
+}
+
+/// The casing rules of a language, which decide the words capitalized wherever they appear and
+/// how a letter is capitalized. Use [CasingRules::for_language] to find the rules for a locale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CasingRules {
+    /// Nothing is capitalized mid-sentence: French, Spanish, Russian and most languages.
+    Plain,
+    /// Months and weekdays are always capitalized. This is the default.
+    #[default]
+    English,
+    /// Every noun is capitalized, including units, months and weekdays: German and Luxembourgish.
+    German,
+    /// `i` capitalizes to `İ` and `ı` to `I`: Turkish and Azerbaijani.
+    Turkish,
+    /// A leading `ij` capitalizes to `IJ`: Dutch.
+    Dutch,
+}
+
+impl CasingRules {
+    /// The rules for a language or locale, such as `de` or `tr-TR`. Unknown languages use
+    /// [CasingRules::Plain], except English, which is also used when there is no language.
+    pub fn for_language(language: &str) -> Self {
+        let language = language
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match language.as_str() {
+            "" | "c" | "en" => Self::English,
+            "de" | "lb" => Self::German,
+            "tr" | "az" => Self::Turkish,
+            "nl" => Self::Dutch,
+            _ => Self::Plain,
+        }
+    }
+
+    /// Is this word capitalized wherever it appears?
+    pub fn capitalizes(&self, word: &Words) -> bool {
+        match self {
+            Self::English => word.is_proper_noun(),
+            Self::German => word.is_noun(),
+            Self::Plain | Self::Turkish | Self::Dutch => false,
+        }
+    }
+
+    /// Write text with its first letter capitalized.
+    pub fn write_capitalized<W: std::fmt::Write>(&self, text: &str, s: &mut W) -> std::fmt::Result {
+        let mut chars = text.chars();
+        let Some(first) = chars.next() else {
+            return Ok(());
+        };
+
+        match (self, first) {
+            (Self::Turkish, 'i') => s.write_char('İ')?,
+            (Self::Turkish, 'ı') => s.write_char('I')?,
+            (Self::Dutch, 'i') if chars.as_str().starts_with('j') => {
+                s.write_str("IJ")?;
+                chars.next();
+            }
+            _ => {
+                for upper in first.to_uppercase() {
+                    s.write_char(upper)?;
+                }
+            }
+        }
+
+        s.write_str(chars.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_casing_rules() {
+        use super::*;
+
+        let capitalized = |rules: CasingRules, text| {
+            let mut s = String::new();
+            rules.write_capitalized(text, &mut s).unwrap();
+            s
+        };
+
+        assert_eq!("Gestern", capitalized(CasingRules::German, "gestern"));
+        assert_eq!(
+            "İki gün önce",
+            capitalized(CasingRules::Turkish, "iki gün önce")
+        );
+        assert_eq!("Irmak", capitalized(CasingRules::Turkish, "ırmak"));
+        assert_eq!("Iki", capitalized(CasingRules::Plain, "iki"));
+        assert_eq!("IJsland", capitalized(CasingRules::Dutch, "ijsland"));
+        assert_eq!("Ánimo", capitalized(CasingRules::Plain, "ánimo"));
+        assert_eq!("", capitalized(CasingRules::Plain, ""));
+
+        assert_eq!(CasingRules::German, CasingRules::for_language("de-AT"));
+        assert_eq!(CasingRules::Turkish, CasingRules::for_language("tr"));
+        assert_eq!(CasingRules::Plain, CasingRules::for_language("fr"));
+        assert_eq!(CasingRules::English, CasingRules::for_language("en_US"));
+
+        let english = CasingRules::English;
+        assert!(english.capitalizes(&Words::Monday));
+        assert!(english.capitalizes(&Words::Variant(
+            crate::enums::Usage::Past,
+            Box::new(Words::January)
+        )));
+        assert!(!english.capitalizes(&Words::Yesterday));
+        assert!(!english.capitalizes(&Words::Day));
+
+        let german = CasingRules::German;
+        assert!(german.capitalizes(&Words::DayPlural));
+        assert!(german.capitalizes(&Words::Noon));
+        assert!(!german.capitalizes(&Words::Yesterday));
+        assert!(!german.capitalizes(&Words::Last));
+        assert!(!CasingRules::Plain.capitalizes(&Words::Monday));
+
+        assert!(!Capitalization::MiddleOfSentence.capitalizes_first());
+        assert!(Capitalization::all()[1..]
+            .iter()
+            .all(Capitalization::capitalizes_first));
+    }
+}
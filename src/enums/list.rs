@@ -0,0 +1,121 @@
+/// Whether a list joins with a conjunction ("3 days and 2 hours") or as a bare run of units ("3
+/// days, 2 hours"), as CLDR distinguishes them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ListKind {
+    #[default]
+    And,
+    Unit,
+}
+
+/// How compact a list is. Narrow lists fall back to short ones, and short lists to wide ones, when
+/// a locale does not provide them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ListWidth {
+    #[default]
+    Wide,
+    Short,
+    Narrow,
+}
+
+/// The kind and width of a list; see [Pattern::List](super::Pattern::List).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ListStyle {
+    pub kind: ListKind,
+    pub width: ListWidth,
+}
+
+impl ListStyle {
+    /// Construct a list style.
+    pub fn new(kind: ListKind, width: ListWidth) -> Self {
+        Self { kind, width }
+    }
+
+    /// Yield all list styles.
+    pub fn all() -> Vec<Self> {
+        let mut all = Vec::new();
+        for kind in [ListKind::And, ListKind::Unit] {
+            for width in [ListWidth::Wide, ListWidth::Short, ListWidth::Narrow] {
+                all.push(Self::new(kind, width));
+            }
+        }
+
+        all
+    }
+
+    /// The next wider style, which is consulted when a locale has no pattern for this one.
+    pub fn wider(&self) -> Option<Self> {
+        match self.width {
+            ListWidth::Wide => None,
+            ListWidth::Short => Some(Self::new(self.kind, ListWidth::Wide)),
+            ListWidth::Narrow => Some(Self::new(self.kind, ListWidth::Short)),
+        }
+    }
+}
+
+/// The CLDR list pattern parts. Two items use [ListPart::Two]; longer lists nest [ListPart::Start]
+/// around [ListPart::Middle]s, around [ListPart::End] for the last two items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ListPart {
+    Start,
+    Middle,
+    End,
+    Two,
+}
+
+impl ListPart {
+    /// Yield all parts.
+    pub fn all() -> [Self; 4] {
+        [Self::Start, Self::Middle, Self::End, Self::Two]
+    }
+
+    /// The part joining the first of `len` items to the rest, if there is more than one. `self`
+    /// is [ListPart::Start] for a whole list and [ListPart::Middle] once nested.
+    pub(crate) fn joining(self, len: usize) -> Option<Self> {
+        match len {
+            0 | 1 => None,
+            2 if self == Self::Start => Some(Self::Two),
+            2 => Some(Self::End),
+            _ => Some(self),
+        }
+    }
+}
+
+impl std::fmt::Display for ListStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self.kind {
+            ListKind::And => "list",
+            ListKind::Unit => "unit_list",
+        })?;
+
+        f.write_str(match self.width {
+            ListWidth::Wide => "",
+            ListWidth::Short => "_short",
+            ListWidth::Narrow => "_narrow",
+        })
+    }
+}
+
+impl std::fmt::Display for ListPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Start => "start",
+            Self::Middle => "middle",
+            Self::End => "end",
+            Self::Two => "two",
+        })
+    }
+}
+
+impl std::str::FromStr for ListPart {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "start" => Self::Start,
+            "middle" => Self::Middle,
+            "end" => Self::End,
+            "two" => Self::Two,
+            x => return Err(anyhow::anyhow!("invalid list part '{}'", x)),
+        })
+    }
+}
@@ -1,4 +1,18 @@
+mod agreement;
+mod casing;
+mod list;
+mod numbering;
+mod pattern;
+mod plural;
+mod usage;
 mod words;
+pub use self::agreement::{Gender, Modifier};
+pub use self::casing::{Capitalization, CasingRules};
+pub use self::list::{ListKind, ListPart, ListStyle, ListWidth};
+pub use self::numbering::NumberingSystem;
+pub use self::pattern::Pattern;
+pub use self::plural::{OrdinalRules, PluralCategory, PluralRules};
+pub use self::usage::Usage;
 pub use self::words::Words;
 
 /// A list of months in order. They are numerically indexed, string indexed and can be
@@ -19,9 +33,9 @@ pub enum Month {
     December,
 }
 
-impl Into<Month> for u32 {
-    fn into(self) -> Month {
-        match self {
+impl From<u32> for Month {
+    fn from(value: u32) -> Self {
+        match value {
             0 => Month::January,
             1 => Month::February,
             2 => Month::March,
@@ -39,40 +53,40 @@ impl Into<Month> for u32 {
     }
 }
 
-impl Into<Words> for Month {
-    fn into(self) -> Words {
-        match self {
-            Self::January => Words::January,
-            Self::February => Words::February,
-            Self::March => Words::March,
-            Self::April => Words::April,
-            Self::May => Words::May,
-            Self::June => Words::June,
-            Self::July => Words::July,
-            Self::August => Words::August,
-            Self::September => Words::September,
-            Self::October => Words::October,
-            Self::November => Words::November,
-            Self::December => Words::December,
+impl From<Month> for Words {
+    fn from(value: Month) -> Self {
+        match value {
+            Month::January => Words::January,
+            Month::February => Words::February,
+            Month::March => Words::March,
+            Month::April => Words::April,
+            Month::May => Words::May,
+            Month::June => Words::June,
+            Month::July => Words::July,
+            Month::August => Words::August,
+            Month::September => Words::September,
+            Month::October => Words::October,
+            Month::November => Words::November,
+            Month::December => Words::December,
         }
     }
 }
 
-impl Into<Option<Month>> for Words {
-    fn into(self) -> Option<Month> {
-        match self {
-            Self::January => Some(Month::January),
-            Self::February => Some(Month::February),
-            Self::March => Some(Month::March),
-            Self::April => Some(Month::April),
-            Self::May => Some(Month::May),
-            Self::June => Some(Month::June),
-            Self::July => Some(Month::July),
-            Self::August => Some(Month::August),
-            Self::September => Some(Month::September),
-            Self::October => Some(Month::October),
-            Self::November => Some(Month::November),
-            Self::December => Some(Month::December),
+impl From<Words> for Option<Month> {
+    fn from(value: Words) -> Self {
+        match value {
+            Words::January => Some(Month::January),
+            Words::February => Some(Month::February),
+            Words::March => Some(Month::March),
+            Words::April => Some(Month::April),
+            Words::May => Some(Month::May),
+            Words::June => Some(Month::June),
+            Words::July => Some(Month::July),
+            Words::August => Some(Month::August),
+            Words::September => Some(Month::September),
+            Words::October => Some(Month::October),
+            Words::November => Some(Month::November),
+            Words::December => Some(Month::December),
             _ => None,
         }
     }
@@ -129,9 +143,9 @@ impl From<chrono::Weekday> for Weekday {
     }
 }
 
-impl Into<Weekday> for u32 {
-    fn into(self) -> Weekday {
-        match self {
+impl From<u32> for Weekday {
+    fn from(value: u32) -> Self {
+        match value {
             // chrono days start with monday
             0 => Weekday::Monday,
             1 => Weekday::Tuesday,
@@ -145,30 +159,30 @@ impl Into<Weekday> for u32 {
     }
 }
 
-impl Into<Words> for Weekday {
-    fn into(self) -> Words {
-        match self {
-            Self::Sunday => Words::Sunday,
-            Self::Monday => Words::Monday,
-            Self::Tuesday => Words::Tuesday,
-            Self::Wednesday => Words::Wednesday,
-            Self::Thursday => Words::Thursday,
-            Self::Friday => Words::Friday,
-            Self::Saturday => Words::Saturday,
+impl From<Weekday> for Words {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Sunday => Words::Sunday,
+            Weekday::Monday => Words::Monday,
+            Weekday::Tuesday => Words::Tuesday,
+            Weekday::Wednesday => Words::Wednesday,
+            Weekday::Thursday => Words::Thursday,
+            Weekday::Friday => Words::Friday,
+            Weekday::Saturday => Words::Saturday,
         }
     }
 }
 
-impl Into<Option<Weekday>> for Words {
-    fn into(self) -> Option<Weekday> {
-        match self {
-            Self::Sunday => Some(Weekday::Sunday),
-            Self::Monday => Some(Weekday::Monday),
-            Self::Tuesday => Some(Weekday::Tuesday),
-            Self::Wednesday => Some(Weekday::Wednesday),
-            Self::Thursday => Some(Weekday::Thursday),
-            Self::Friday => Some(Weekday::Friday),
-            Self::Saturday => Some(Weekday::Saturday),
+impl From<Words> for Option<Weekday> {
+    fn from(value: Words) -> Self {
+        match value {
+            Words::Sunday => Some(Weekday::Sunday),
+            Words::Monday => Some(Weekday::Monday),
+            Words::Tuesday => Some(Weekday::Tuesday),
+            Words::Wednesday => Some(Weekday::Wednesday),
+            Words::Thursday => Some(Weekday::Thursday),
+            Words::Friday => Some(Weekday::Friday),
+            Words::Saturday => Some(Weekday::Saturday),
             _ => None,
         }
     }
@@ -0,0 +1,204 @@
+/// The CLDR numbering systems with decimal digits, which numbers in rendered phrases are written
+/// in. Locales declare one with a key such as `numbering: arab`; see
+/// [Translator::numbering](crate::translator::Translator::numbering).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NumberingSystem {
+    /// ASCII digits, `0123456789`. This is the default.
+    #[default]
+    Latn,
+    /// Arabic-Indic digits, `٠١٢٣٤٥٦٧٨٩`.
+    Arab,
+    /// Extended Arabic-Indic digits used for Persian and Urdu, `۰۱۲۳۴۵۶۷۸۹`.
+    Arabext,
+    /// Bengali digits, `০১২৩৪৫৬৭৮৯`.
+    Beng,
+    /// Devanagari digits used for Hindi, Marathi and Nepali, `०१२३४५६७८९`.
+    Deva,
+    /// Full-width digits, `０１２３４５６７８９`.
+    Fullwide,
+    /// Gujarati digits, `૦૧૨૩૪૫૬૭૮૯`.
+    Gujr,
+    /// Gurmukhi digits, `੦੧੨੩੪੫੬੭੮੯`.
+    Guru,
+    /// Chinese decimal digits, `〇一二三四五六七八九`.
+    Hanidec,
+    /// Khmer digits, `០១២៣៤៥៦៧៨៩`.
+    Khmr,
+    /// Kannada digits, `೦೧೨೩೪೫೬೭೮೯`.
+    Knda,
+    /// Lao digits, `໐໑໒໓໔໕໖໗໘໙`.
+    Laoo,
+    /// Malayalam digits, `൦൧൨൩൪൫൬൭൮൯`.
+    Mlym,
+    /// Myanmar digits, `၀၁၂၃၄၅၆၇၈၉`.
+    Mymr,
+    /// Odia digits, `୦୧୨୩୪୫୬୭୮୯`.
+    Orya,
+    /// Tamil decimal digits, `௦௧௨௩௪௫௬௭௮௯`.
+    Tamldec,
+    /// Telugu digits, `౦౧౨౩౪౫౬౭౮౯`.
+    Telu,
+    /// Thai digits, `๐๑๒๓๔๕๖๗๘๙`.
+    Thai,
+    /// Tibetan digits, `༠༡༢༣༤༥༦༧༨༩`.
+    Tibt,
+}
+
+impl NumberingSystem {
+    /// Yield every numbering system.
+    pub fn all() -> [Self; 19] {
+        [
+            Self::Latn,
+            Self::Arab,
+            Self::Arabext,
+            Self::Beng,
+            Self::Deva,
+            Self::Fullwide,
+            Self::Gujr,
+            Self::Guru,
+            Self::Hanidec,
+            Self::Khmr,
+            Self::Knda,
+            Self::Laoo,
+            Self::Mlym,
+            Self::Mymr,
+            Self::Orya,
+            Self::Tamldec,
+            Self::Telu,
+            Self::Thai,
+            Self::Tibt,
+        ]
+    }
+
+    /// The digits from zero to nine.
+    pub fn digits(&self) -> [char; 10] {
+        match self {
+            Self::Hanidec => ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'],
+            _ => {
+                let zero = self.zero() as u32;
+                // every other system has its digits in sequence
+                std::array::from_fn(|x| char::from_u32(zero + x as u32).unwrap())
+            }
+        }
+    }
+
+    fn zero(&self) -> char {
+        match self {
+            Self::Latn => '0',
+            Self::Arab => '\u{0660}',
+            Self::Arabext => '\u{06F0}',
+            Self::Beng => '\u{09E6}',
+            Self::Deva => '\u{0966}',
+            Self::Fullwide => '\u{FF10}',
+            Self::Gujr => '\u{0AE6}',
+            Self::Guru => '\u{0A66}',
+            Self::Hanidec => '\u{3007}',
+            Self::Khmr => '\u{17E0}',
+            Self::Knda => '\u{0CE6}',
+            Self::Laoo => '\u{0ED0}',
+            Self::Mlym => '\u{0D66}',
+            Self::Mymr => '\u{1040}',
+            Self::Orya => '\u{0B66}',
+            Self::Tamldec => '\u{0BE6}',
+            Self::Telu => '\u{0C66}',
+            Self::Thai => '\u{0E50}',
+            Self::Tibt => '\u{0F20}',
+        }
+    }
+
+    /// Write a whole number in these digits. The sign is left as `-`.
+    pub fn write_number<W: std::fmt::Write>(&self, number: i64, s: &mut W) -> std::fmt::Result {
+        if *self == Self::Latn {
+            return write!(s, "{}", number);
+        }
+
+        let digits = self.digits();
+        for ch in number.to_string().chars() {
+            match ch.to_digit(10) {
+                Some(digit) => s.write_char(digits[digit as usize])?,
+                None => s.write_char(ch)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A whole number in these digits.
+    pub fn number(&self, number: i64) -> String {
+        let mut s = String::new();
+        // writing to a string never fails
+        self.write_number(number, &mut s).unwrap();
+        s
+    }
+}
+
+impl std::fmt::Display for NumberingSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Latn => "latn",
+            Self::Arab => "arab",
+            Self::Arabext => "arabext",
+            Self::Beng => "beng",
+            Self::Deva => "deva",
+            Self::Fullwide => "fullwide",
+            Self::Gujr => "gujr",
+            Self::Guru => "guru",
+            Self::Hanidec => "hanidec",
+            Self::Khmr => "khmr",
+            Self::Knda => "knda",
+            Self::Laoo => "laoo",
+            Self::Mlym => "mlym",
+            Self::Mymr => "mymr",
+            Self::Orya => "orya",
+            Self::Tamldec => "tamldec",
+            Self::Telu => "telu",
+            Self::Thai => "thai",
+            Self::Tibt => "tibt",
+        })
+    }
+}
+
+impl std::str::FromStr for NumberingSystem {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .into_iter()
+            .find(|system| system.to_string() == s)
+            .ok_or_else(|| anyhow::anyhow!("invalid numbering system '{}'", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_numbering_system() {
+        use super::*;
+
+        let cases = [
+            (NumberingSystem::Latn, "1978"),
+            (NumberingSystem::Arab, "١٩٧٨"),
+            (NumberingSystem::Arabext, "۱۹۷۸"),
+            (NumberingSystem::Deva, "१९७८"),
+            (NumberingSystem::Thai, "๑๙๗๘"),
+            (NumberingSystem::Hanidec, "一九七八"),
+            (NumberingSystem::Fullwide, "１９７８"),
+        ];
+
+        for (system, expected) in cases {
+            assert_eq!(expected, system.number(1978), "{}", system);
+        }
+
+        assert_eq!("-٤٥", NumberingSystem::Arab.number(-45));
+        assert_eq!("〇", NumberingSystem::Hanidec.number(0));
+
+        for system in NumberingSystem::all() {
+            assert_eq!(system, system.to_string().parse().unwrap());
+            let digits = system.digits();
+            let distinct = digits.iter().collect::<std::collections::BTreeSet<_>>();
+            assert_eq!(10, distinct.len(), "{}", system);
+        }
+
+        assert!("roman".parse::<NumberingSystem>().is_err());
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{
+    enums::{
+        Gender, ListKind, ListPart, ListStyle, ListWidth, Modifier, PluralCategory, Usage, Words,
+    },
+    time_boundary::TimeBoundary,
+    translator::{Template, Token},
+};
+
+/// Sentence patterns which a locale can supply to control word order and spacing. Each is a
+/// format string, as understood by [Translator::format](crate::translator::Translator::format),
+/// containing slots which are filled in when rendering:
+///
+/// - [Pattern::Past], [Pattern::Future], [Pattern::ShortPast], [Pattern::ShortFuture] and
+///   [Pattern::Standalone] wrap a whole duration, given as `%{duration}`: `il y a %{duration}` or
+///   `%{duration}前`. Words inside it are translated with the pattern's [Usage].
+/// - [Pattern::Quantity] and [Pattern::UnitQuantity] build a number of a unit from `%{count}` and
+///   `%{unit}`: `%{count}%{unit}`.
+/// - [Pattern::AbbreviatedQuantity] does the same for a unit abbreviated to a [ListWidth]:
+///   `%{count}%{unit}`.
+/// - [Pattern::PluralQuantity] does either for a [PluralCategory], and may leave out the count:
+///   `%{unit}` for the Arabic dual.
+/// - [Pattern::Ordinal] and [Pattern::UnitOrdinal] place an ordinal before its unit, from
+///   `%{nth}` and `%{unit}`: `第%{nth}%{unit}`.
+/// - [Pattern::DayOfMonth] writes a day of the month from `%{count}`, the bare number, or
+///   `%{nth}`, the ordinal: `%{count}日`.
+/// - [Pattern::Modified] places a modifier and its noun: `la %{noun} %{modifier}`.
+///   [Pattern::NounModified] does so for one noun, and may replace both: `去年`.
+/// - [Pattern::List] joins two items, `%{0}` and `%{1}`, as CLDR list patterns do: `%{0}、%{1}`.
+///
+/// Locales need not supply any of them; see [Pattern::default_template].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Pattern {
+    /// A duration in the past, such as "3 days ago".
+    Past,
+    /// A duration in the future, such as "3 days from now".
+    Future,
+    /// A compact duration in the past, such as "3d ago".
+    ShortPast,
+    /// A compact duration in the future, such as "in 3d".
+    ShortFuture,
+    /// A standalone duration, neither past nor future, such as "3 days".
+    Standalone,
+    /// A number of any unit, such as "3 days".
+    Quantity,
+    /// A number of a specific unit, taking precedence over [Pattern::Quantity].
+    UnitQuantity(TimeBoundary),
+    /// A number of an abbreviated unit, such as "3 hr." or "3h", with keys `quantity_short` and
+    /// `quantity_narrow`. Narrow patterns fall back to short ones, and short ones to
+    /// [Pattern::Quantity].
+    AbbreviatedQuantity(ListWidth),
+    /// A number of any unit whose count falls in a [PluralCategory], taking precedence over the
+    /// patterns above, such as `quantity_two: "%{unit}"` for the Arabic dual, which needs no
+    /// numeral. Keys are `quantity`, then `_short` or `_narrow` if not wide, then the category.
+    /// Narrower widths fall back to wider ones.
+    PluralQuantity(ListWidth, PluralCategory),
+    /// An ordinal of any unit, such as "2nd week".
+    Ordinal,
+    /// An ordinal of a specific unit, taking precedence over [Pattern::Ordinal].
+    UnitOrdinal(TimeBoundary),
+    /// A day of the month, such as "3rd".
+    DayOfMonth,
+    /// A noun with a [Modifier], such as "last week", built from `%{modifier}` and `%{noun}`.
+    /// Patterns may be given for a modifier, a [Gender], or both, so French can use
+    /// `last_modified_feminine: "la %{noun} %{modifier}"` while `modified: "%{modifier} %{noun}"`
+    /// covers "cette semaine". The most specific is used, preferring the modifier over the gender.
+    Modified(Option<Modifier>, Option<Gender>),
+    /// One noun with a [Modifier], taking precedence over [Pattern::Modified], such as
+    /// `last_year_modified: 去年` or `next_monday_modified: "来週の%{noun}"`.
+    NounModified(Modifier, Box<Words>),
+    /// One part of a list, such as `list_end: "%{0}, %{and} %{1}"` for an Oxford comma. Keys are
+    /// `list` or `unit_list`, then `_short` or `_narrow` if not wide, then the part: for example
+    /// `unit_list_narrow_two`. Narrower styles fall back to wider ones.
+    List(ListStyle, ListPart),
+}
+
+impl Pattern {
+    /// Yield every pattern.
+    pub fn all() -> Vec<Self> {
+        let mut all = vec![
+            Self::Past,
+            Self::Future,
+            Self::ShortPast,
+            Self::ShortFuture,
+            Self::Standalone,
+            Self::Quantity,
+        ];
+
+        all.extend(
+            TimeBoundary::all()
+                .into_iter()
+                .rev()
+                .map(Self::UnitQuantity),
+        );
+        all.push(Self::AbbreviatedQuantity(ListWidth::Short));
+        all.push(Self::AbbreviatedQuantity(ListWidth::Narrow));
+        for width in [ListWidth::Wide, ListWidth::Short, ListWidth::Narrow] {
+            all.extend(
+                PluralCategory::all()
+                    .into_iter()
+                    .map(|category| Self::PluralQuantity(width, category)),
+            );
+        }
+
+        all.push(Self::Ordinal);
+        all.extend(TimeBoundary::all().into_iter().rev().map(Self::UnitOrdinal));
+        all.push(Self::DayOfMonth);
+        let modifiers = std::iter::once(None).chain(Modifier::all().into_iter().map(Some));
+        for modifier in modifiers {
+            all.push(Self::Modified(modifier, None));
+            all.extend(
+                Gender::all()
+                    .into_iter()
+                    .map(|gender| Self::Modified(modifier, Some(gender))),
+            );
+        }
+
+        let nouns = TimeBoundary::all()
+            .into_iter()
+            .rev()
+            .map(Words::from)
+            .chain([
+                Words::Sunday,
+                Words::Monday,
+                Words::Tuesday,
+                Words::Wednesday,
+                Words::Thursday,
+                Words::Friday,
+                Words::Saturday,
+            ]);
+        for noun in nouns {
+            all.extend(
+                Modifier::all()
+                    .into_iter()
+                    .map(|modifier| Self::NounModified(modifier, Box::new(noun.clone()))),
+            );
+        }
+
+        for style in ListStyle::all() {
+            all.extend(
+                ListPart::all()
+                    .into_iter()
+                    .map(|part| Self::List(style, part)),
+            );
+        }
+
+        all
+    }
+
+    /// The names of the slots this pattern fills.
+    pub fn slots(&self) -> &'static [&'static str] {
+        match self {
+            Self::Past | Self::Future | Self::ShortPast | Self::ShortFuture | Self::Standalone => {
+                &["duration"]
+            }
+            Self::Quantity
+            | Self::UnitQuantity(_)
+            | Self::AbbreviatedQuantity(_)
+            | Self::PluralQuantity(..) => &["count", "unit"],
+            Self::Ordinal | Self::UnitOrdinal(_) => &["nth", "unit"],
+            Self::DayOfMonth => &["count", "nth"],
+            Self::Modified(..) | Self::NounModified(..) => &["modifier", "noun"],
+            Self::List(..) => &["0", "1"],
+        }
+    }
+
+    /// The [Usage] of words in the duration this pattern wraps.
+    pub fn usage(&self) -> Option<Usage> {
+        match self {
+            Self::Past | Self::ShortPast => Some(Usage::Past),
+            Self::Future | Self::ShortFuture => Some(Usage::Future),
+            Self::Standalone => Some(Usage::Standalone),
+            Self::Quantity
+            | Self::UnitQuantity(_)
+            | Self::AbbreviatedQuantity(_)
+            | Self::PluralQuantity(..)
+            | Self::Ordinal
+            | Self::UnitOrdinal(_)
+            | Self::DayOfMonth
+            | Self::Modified(..)
+            | Self::NounModified(..)
+            | Self::List(..) => None,
+        }
+    }
+
+    /// The English word order used when a locale has no pattern: `%{duration} %{ago}`,
+    /// `%{duration} %{from now}`, `%{in} %{duration}`, `%{duration}`, `%{count} %{unit}`
+    /// (`%{count}%{unit}` when narrow), `%{nth} %{unit}`, `%{nth}` and `%{modifier} %{noun}`. Lists join with `, ` except for the last two items, which join with
+    /// ` %{and} `; unit lists only use `, `, or a space when narrow.
+    pub fn default_template(&self) -> Template {
+        let slot = |name: &str| Token::Unknown(name.to_string());
+        let space = || Token::Literal(" ".into());
+
+        Template::from(match self {
+            Self::Past | Self::ShortPast => {
+                vec![slot("duration"), space(), Token::Word(Words::Ago)]
+            }
+            Self::Future => vec![slot("duration"), space(), Token::Word(Words::FromNow)],
+            Self::ShortFuture => vec![Token::Word(Words::In), space(), slot("duration")],
+            Self::Standalone => vec![slot("duration")],
+            Self::Quantity | Self::UnitQuantity(_) => vec![slot("count"), space(), slot("unit")],
+            Self::AbbreviatedQuantity(ListWidth::Narrow)
+            | Self::PluralQuantity(ListWidth::Narrow, _) => vec![slot("count"), slot("unit")],
+            Self::AbbreviatedQuantity(_) | Self::PluralQuantity(..) => {
+                vec![slot("count"), space(), slot("unit")]
+            }
+            Self::Ordinal | Self::UnitOrdinal(_) => vec![slot("nth"), space(), slot("unit")],
+            Self::DayOfMonth => vec![slot("nth")],
+            Self::Modified(..) | Self::NounModified(..) => {
+                vec![slot("modifier"), space(), slot("noun")]
+            }
+            Self::List(style, part) => {
+                let separator = match (style.kind, style.width, part) {
+                    (ListKind::And, _, ListPart::End | ListPart::Two) => {
+                        vec![space(), Token::Word(Words::And), space()]
+                    }
+                    (ListKind::Unit, ListWidth::Narrow, _) => vec![space()],
+                    _ => vec![Token::Literal(", ".into())],
+                };
+
+                let mut tokens = vec![slot("0")];
+                tokens.extend(separator);
+                tokens.push(slot("1"));
+                tokens
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Past => f.write_str("past"),
+            Self::Future => f.write_str("future"),
+            Self::ShortPast => f.write_str("short_past"),
+            Self::ShortFuture => f.write_str("short_future"),
+            Self::Standalone => f.write_str("standalone"),
+            Self::Quantity => f.write_str("quantity"),
+            Self::UnitQuantity(boundary) => write!(f, "{}_quantity", Words::from(boundary.clone())),
+            Self::AbbreviatedQuantity(width) => match width {
+                ListWidth::Narrow => f.write_str("quantity_narrow"),
+                _ => f.write_str("quantity_short"),
+            },
+            Self::PluralQuantity(width, category) => match width {
+                ListWidth::Wide => write!(f, "quantity_{}", category),
+                _ => write!(f, "{}_{}", Self::AbbreviatedQuantity(*width), category),
+            },
+            Self::Ordinal => f.write_str("ordinal"),
+            Self::UnitOrdinal(boundary) => write!(f, "{}_ordinal", Words::from(boundary.clone())),
+            Self::DayOfMonth => f.write_str("day_of_month"),
+            Self::Modified(modifier, gender) => {
+                if let Some(modifier) = modifier {
+                    write!(f, "{}_", Words::from(*modifier))?;
+                }
+
+                f.write_str("modified")?;
+                match gender {
+                    Some(gender) => write!(f, "_{}", gender),
+                    None => Ok(()),
+                }
+            }
+            Self::NounModified(modifier, noun) => {
+                write!(f, "{}_{}_modified", Words::from(*modifier), noun)
+            }
+            Self::List(style, part) => write!(f, "{}_{}", style, part),
+        }
+    }
+}
+
+impl std::str::FromStr for Pattern {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "past" => Self::Past,
+            "future" => Self::Future,
+            "short_past" => Self::ShortPast,
+            "short_future" => Self::ShortFuture,
+            "standalone" => Self::Standalone,
+            "quantity" => Self::Quantity,
+            "quantity_short" => Self::AbbreviatedQuantity(ListWidth::Short),
+            "quantity_narrow" => Self::AbbreviatedQuantity(ListWidth::Narrow),
+            "ordinal" => Self::Ordinal,
+            "day_of_month" => Self::DayOfMonth,
+            x => {
+                if let Some((modifier, rest)) = x.split_once("modified") {
+                    let parse_modifier = |word| {
+                        Option::<Modifier>::from(Words::from_str(word)?)
+                            .ok_or_else(|| anyhow::anyhow!("invalid modifier '{}'", word))
+                    };
+
+                    let modifier = match modifier.strip_suffix('_') {
+                        Some(word) if rest.is_empty() && parse_modifier(word).is_err() => {
+                            let (modifier, noun) = word
+                                .split_once('_')
+                                .ok_or_else(|| anyhow::anyhow!("invalid pattern '{}'", x))?;
+                            return Ok(Self::NounModified(
+                                parse_modifier(modifier)?,
+                                Box::new(noun.parse()?),
+                            ));
+                        }
+                        Some(word) => Some(parse_modifier(word)?),
+                        None if modifier.is_empty() => None,
+                        None => return Err(anyhow::anyhow!("invalid pattern '{}'", x)),
+                    };
+
+                    let gender = match rest.strip_prefix('_') {
+                        Some(gender) => Some(gender.parse()?),
+                        None if rest.is_empty() => None,
+                        None => return Err(anyhow::anyhow!("invalid pattern '{}'", x)),
+                    };
+
+                    return Ok(Self::Modified(modifier, gender));
+                }
+
+                let list = [("unit_list_", ListKind::Unit), ("list_", ListKind::And)]
+                    .into_iter()
+                    .find_map(|(prefix, kind)| Some((x.strip_prefix(prefix)?, kind)));
+                if let Some((rest, kind)) = list {
+                    let (width, part) = match rest.split_once('_') {
+                        Some(("short", part)) => (ListWidth::Short, part),
+                        Some(("narrow", part)) => (ListWidth::Narrow, part),
+                        _ => (ListWidth::Wide, rest),
+                    };
+
+                    return Ok(Self::List(ListStyle::new(kind, width), part.parse()?));
+                }
+
+                let plural = [
+                    ("quantity_short_", ListWidth::Short),
+                    ("quantity_narrow_", ListWidth::Narrow),
+                    ("quantity_", ListWidth::Wide),
+                ]
+                .into_iter()
+                .find_map(|(prefix, width)| Some((x.strip_prefix(prefix)?, width)));
+                if let Some((category, width)) = plural {
+                    return Ok(Self::PluralQuantity(width, category.parse()?));
+                }
+
+                let unit = |suffix| {
+                    x.strip_suffix(suffix)
+                        .and_then(|word| Words::from_str(word).ok())
+                        .and_then(Option::<TimeBoundary>::from)
+                };
+
+                return unit("_quantity")
+                    .map(Self::UnitQuantity)
+                    .or_else(|| unit("_ordinal").map(Self::UnitOrdinal))
+                    .ok_or_else(|| anyhow::anyhow!("invalid pattern '{}'", x));
+            }
+        })
+    }
+}
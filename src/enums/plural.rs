@@ -0,0 +1,420 @@
+/// The CLDR plural categories. Each language uses a subset of these to decide which form of a
+/// word follows a number; English only uses [PluralCategory::One] and [PluralCategory::Other],
+/// while Arabic uses all six. See [PluralRules].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Yield all categories in order.
+    pub fn all() -> [Self; 6] {
+        [
+            Self::Zero,
+            Self::One,
+            Self::Two,
+            Self::Few,
+            Self::Many,
+            Self::Other,
+        ]
+    }
+}
+
+impl std::fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        })
+    }
+}
+
+impl std::str::FromStr for PluralCategory {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "zero" => Self::Zero,
+            "one" => Self::One,
+            "two" => Self::Two,
+            "few" => Self::Few,
+            "many" => Self::Many,
+            "other" => Self::Other,
+            x => return Err(anyhow::anyhow!("invalid plural category '{}'", x)),
+        })
+    }
+}
+
+/// The CLDR plural rules for whole numbers, grouped by the languages which share them. Use
+/// [PluralRules::for_language] to find the rules for a locale, and [PluralRules::category] to
+/// classify a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PluralRules {
+    /// No plural forms: Japanese, Chinese, Korean, Thai, Vietnamese, Indonesian.
+    Invariant,
+    /// `one` for 1, `other` otherwise: English, German, Dutch, the Scandinavian languages,
+    /// Finnish, Greek, Hungarian, Turkish and many more. This is the default.
+    #[default]
+    English,
+    /// `one` for 0 and 1, `many` for non-zero multiples of a million: French and Portuguese.
+    French,
+    /// `one` for 1, `many` for non-zero multiples of a million: Spanish, Italian, Catalan.
+    Spanish,
+    /// `one` for 1, 21, 31…, `few` for 2–4, 22–24…, `many` otherwise: Russian, Ukrainian,
+    /// Belarusian.
+    Russian,
+    /// `one` for 1 only, `few` for 2–4, 22–24…, `many` otherwise.
+    Polish,
+    /// `one` for 1, `few` for 2–4, `other` otherwise: Czech and Slovak.
+    Czech,
+    /// `zero`, `one`, `two`, `few` for 3–10 and 103–110…, `many` for 11–99 and 111–199…,
+    /// `other` otherwise.
+    Arabic,
+    /// `zero`, `one`, `two`, `few` for 3, `many` for 6, `other` otherwise.
+    Welsh,
+    /// `one` for 1, `two` for 2, `other` otherwise.
+    Hebrew,
+}
+
+impl PluralRules {
+    /// The rules for a language or locale, such as `ru` or `pt-BR`. Unknown languages use
+    /// [PluralRules::English].
+    pub fn for_language(language: &str) -> Self {
+        let language = language
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match language.as_str() {
+            "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "lo" | "my" => Self::Invariant,
+            "fr" | "pt" => Self::French,
+            "es" | "it" | "ca" => Self::Spanish,
+            "ru" | "uk" | "be" => Self::Russian,
+            "pl" => Self::Polish,
+            "cs" | "sk" => Self::Czech,
+            "ar" => Self::Arabic,
+            "cy" => Self::Welsh,
+            "he" | "iw" => Self::Hebrew,
+            _ => Self::English,
+        }
+    }
+
+    /// A whole number in a category, if any falls in it: the smallest positive one, such as 2 for
+    /// [PluralCategory::Few] in Russian, else zero or a million.
+    pub fn sample(&self, category: PluralCategory) -> Option<i64> {
+        (1..=200)
+            .chain([0, 1_000_000])
+            .find(|n| self.category(*n) == category)
+    }
+
+    /// The category a whole number falls in. Negative numbers are treated as their magnitude.
+    pub fn category(&self, n: i64) -> PluralCategory {
+        let n = n.unsigned_abs();
+        let (n10, n100) = (n % 10, n % 100);
+
+        match self {
+            Self::Invariant => PluralCategory::Other,
+            Self::English => match n {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            Self::French => match n {
+                0 | 1 => PluralCategory::One,
+                _ if n.is_multiple_of(1_000_000) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Self::Spanish => match n {
+                1 => PluralCategory::One,
+                _ if n != 0 && n.is_multiple_of(1_000_000) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Self::Russian => match (n10, n100) {
+                (1, _) if n100 != 11 => PluralCategory::One,
+                (2..=4, _) if !(12..=14).contains(&n100) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            Self::Polish => match (n10, n100) {
+                _ if n == 1 => PluralCategory::One,
+                (2..=4, _) if !(12..=14).contains(&n100) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            Self::Czech => match n {
+                1 => PluralCategory::One,
+                2..=4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            Self::Arabic => match n {
+                0 => PluralCategory::Zero,
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ if (3..=10).contains(&n100) => PluralCategory::Few,
+                _ if (11..=99).contains(&n100) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Self::Welsh => match n {
+                0 => PluralCategory::Zero,
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                3 => PluralCategory::Few,
+                6 => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Self::Hebrew => match n {
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ => PluralCategory::Other,
+            },
+        }
+    }
+}
+
+/// The CLDR ordinal rules for whole numbers, which choose the [PluralCategory] of an ordinal
+/// suffix such as the "nd" of "22nd". Use [OrdinalRules::for_language] to find the rules for a
+/// locale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OrdinalRules {
+    /// `other` only: German, Spanish, Portuguese, Russian, Japanese, Chinese, Arabic and most
+    /// languages which write ordinals with a single mark.
+    Invariant,
+    /// `one` for 1, 21, 31…, `two` for 2, 22…, `few` for 3, 23…, `other` otherwise, including 11,
+    /// 12 and 13. This is the default.
+    #[default]
+    English,
+    /// `one` for 1 only: French, Malay, Armenian, Vietnamese.
+    French,
+    /// `many` for 8, 11, 80 and 800: Italian.
+    Italian,
+    /// `one` for 1, 2, 21, 22…, but not 11 or 12: Swedish.
+    Swedish,
+    /// `one` for 1 and 3, `two` for 2, `few` for 4: Catalan.
+    Catalan,
+    /// `one` for 1 and 5: Hungarian.
+    Hungarian,
+    /// `zero` for 0, 7, 8 and 9, `one` for 1, `two` for 2, `few` for 3 and 4, `many` for 5 and 6:
+    /// Welsh.
+    Welsh,
+    /// `one` for 1, `two` for 2 and 3, `few` for 4, `many` for 6: Hindi and Gujarati.
+    Hindi,
+}
+
+impl OrdinalRules {
+    /// The rules for a language or locale, such as `fr` or `en-GB`. Unknown languages use
+    /// [OrdinalRules::Invariant], except English, which is also used when there is no language.
+    pub fn for_language(language: &str) -> Self {
+        let language = language
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match language.as_str() {
+            "" | "c" | "en" => Self::English,
+            "fr" | "ms" | "hy" | "vi" => Self::French,
+            "it" => Self::Italian,
+            "sv" => Self::Swedish,
+            "ca" => Self::Catalan,
+            "hu" => Self::Hungarian,
+            "cy" => Self::Welsh,
+            "hi" | "gu" => Self::Hindi,
+            _ => Self::Invariant,
+        }
+    }
+
+    /// The category the ordinal of a whole number falls in. Negative numbers are treated as their
+    /// magnitude.
+    pub fn category(&self, n: i64) -> PluralCategory {
+        let n = n.unsigned_abs();
+        let (n10, n100) = (n % 10, n % 100);
+
+        match self {
+            Self::Invariant => PluralCategory::Other,
+            Self::English => match (n10, n100) {
+                (1, _) if n100 != 11 => PluralCategory::One,
+                (2, _) if n100 != 12 => PluralCategory::Two,
+                (3, _) if n100 != 13 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            Self::French => match n {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            Self::Italian => match n {
+                8 | 11 | 80 | 800 => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Self::Swedish => match (n10, n100) {
+                (1 | 2, _) if n100 != 11 && n100 != 12 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            Self::Catalan => match n {
+                1 | 3 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            Self::Hungarian => match n {
+                1 | 5 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            Self::Welsh => match n {
+                0 | 7 | 8 | 9 => PluralCategory::Zero,
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                3 | 4 => PluralCategory::Few,
+                5 | 6 => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Self::Hindi => match n {
+                1 => PluralCategory::One,
+                2 | 3 => PluralCategory::Two,
+                4 => PluralCategory::Few,
+                6 => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_plural_rules() {
+        use super::*;
+        use PluralCategory::*;
+
+        let cases: &[(&str, &[(i64, PluralCategory)])] = &[
+            (
+                "en-US",
+                &[(0, Other), (1, One), (2, Other), (-1, One), (11, Other)],
+            ),
+            ("ja", &[(0, Other), (1, Other), (2, Other)]),
+            ("fr", &[(0, One), (1, One), (2, Other), (1_000_000, Many)]),
+            ("es", &[(0, Other), (1, One), (2, Other), (2_000_000, Many)]),
+            (
+                "ru",
+                &[
+                    (0, Many),
+                    (1, One),
+                    (2, Few),
+                    (5, Many),
+                    (11, Many),
+                    (12, Many),
+                    (21, One),
+                    (22, Few),
+                    (101, One),
+                    (111, Many),
+                ],
+            ),
+            (
+                "pl",
+                &[(1, One), (2, Few), (5, Many), (21, Many), (22, Few)],
+            ),
+            ("cs", &[(1, One), (3, Few), (5, Other), (22, Other)]),
+            (
+                "ar",
+                &[
+                    (0, Zero),
+                    (1, One),
+                    (2, Two),
+                    (3, Few),
+                    (10, Few),
+                    (11, Many),
+                    (99, Many),
+                    (100, Other),
+                    (102, Other),
+                    (103, Few),
+                    (111, Many),
+                ],
+            ),
+            (
+                "cy",
+                &[
+                    (0, Zero),
+                    (1, One),
+                    (2, Two),
+                    (3, Few),
+                    (6, Many),
+                    (4, Other),
+                ],
+            ),
+            ("he", &[(1, One), (2, Two), (3, Other)]),
+        ];
+
+        for (language, cases) in cases {
+            let rules = PluralRules::for_language(language);
+            for (n, category) in *cases {
+                assert_eq!(*category, rules.category(*n), "{} {}", language, n);
+            }
+        }
+
+        for category in PluralCategory::all() {
+            assert_eq!(category, category.to_string().parse().unwrap());
+        }
+
+        let russian = PluralRules::for_language("ru");
+        assert_eq!(Some(2), russian.sample(Few));
+        assert_eq!(Some(5), russian.sample(Many));
+        assert_eq!(Some(0), PluralRules::Arabic.sample(Zero));
+        assert_eq!(None, russian.sample(Other));
+        assert_eq!(Some(1_000_000), PluralRules::French.sample(Many));
+    }
+
+    #[test]
+    fn test_ordinal_rules() {
+        use super::*;
+        use PluralCategory::*;
+
+        let cases: &[(&str, &[(i64, PluralCategory)])] = &[
+            (
+                "en-US",
+                &[
+                    (1, One),
+                    (2, Two),
+                    (3, Few),
+                    (4, Other),
+                    (11, Other),
+                    (12, Other),
+                    (13, Other),
+                    (21, One),
+                    (22, Two),
+                    (23, Few),
+                    (101, One),
+                    (111, Other),
+                    (-2, Two),
+                ],
+            ),
+            ("", &[(1, One), (12, Other)]),
+            ("de", &[(1, Other), (2, Other), (3, Other)]),
+            ("fr", &[(1, One), (2, Other), (21, Other)]),
+            ("it", &[(8, Many), (11, Many), (80, Many), (18, Other)]),
+            (
+                "sv",
+                &[(1, One), (2, One), (11, Other), (22, One), (23, Other)],
+            ),
+            ("ca", &[(1, One), (2, Two), (3, One), (4, Few), (5, Other)]),
+            ("hu", &[(1, One), (5, One), (2, Other)]),
+            (
+                "cy",
+                &[(0, Zero), (7, Zero), (3, Few), (6, Many), (10, Other)],
+            ),
+            ("hi", &[(1, One), (3, Two), (4, Few), (6, Many), (5, Other)]),
+        ];
+
+        for (language, cases) in cases {
+            let rules = OrdinalRules::for_language(language);
+            for (n, category) in *cases {
+                assert_eq!(*category, rules.category(*n), "{} {}", language, n);
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/// The construction a word appears in. Some languages inflect units differently depending on it:
+/// German uses the dative "Tagen" after "vor" and "in", but "Tage" for a bare duration. Locales
+/// give these as variants of a word, such as `past.days`; see [Words::Variant](super::Words).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Usage {
+    /// Inside a [Pattern::Past](super::Pattern::Past) or
+    /// [Pattern::ShortPast](super::Pattern::ShortPast).
+    Past,
+    /// Inside a [Pattern::Future](super::Pattern::Future) or
+    /// [Pattern::ShortFuture](super::Pattern::ShortFuture).
+    Future,
+    /// Inside a [Pattern::Standalone](super::Pattern::Standalone), which is neither past nor
+    /// future.
+    Standalone,
+}
+
+impl Usage {
+    /// Yield all usages.
+    pub fn all() -> [Self; 3] {
+        [Self::Past, Self::Future, Self::Standalone]
+    }
+}
+
+impl std::fmt::Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Past => "past",
+            Self::Future => "future",
+            Self::Standalone => "standalone",
+        })
+    }
+}
+
+impl std::str::FromStr for Usage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "past" => Self::Past,
+            "future" => Self::Future,
+            "standalone" => Self::Standalone,
+            x => return Err(anyhow::anyhow!("invalid usage '{}'", x)),
+        })
+    }
+}
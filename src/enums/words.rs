@@ -1,3 +1,6 @@
+use super::{Gender, ListWidth, Modifier, Pattern, PluralCategory, Usage};
+use crate::time_boundary::TimeBoundary;
+
 /// A list of words used in the [Translator](crate::translator::Translator). Each one of these
 /// corresponds to a format string. The formatters that implement
 /// [FormatGenerator](crate::approximate::format_generator::FormatGenerator) generate strings
@@ -17,7 +20,6 @@ pub enum Words {
     October,
     November,
     December,
-    Suffix(u8),
     Noon,
     Midnight,
     PM,
@@ -30,6 +32,8 @@ pub enum Words {
     At,
     Ago,
     Last,
+    Next,
+    This,
     Year,
     Week,
     Month,
@@ -54,9 +58,210 @@ pub enum Words {
     Thursday,
     Friday,
     Saturday,
+    /// The language of a locale, such as `ru`, which selects its [PluralRules](super::PluralRules). Optional; locales
+    /// without it use English rules.
+    Language,
+    /// The [NumberingSystem](super::NumberingSystem) numbers are written in, such as `arab`.
+    /// Optional; locales without it use ASCII digits.
+    Numbering,
+    /// The form of a unit for a [PluralCategory], such as `day_few`. Optional; when missing, the
+    /// singular word is used for [PluralCategory::One] and the plural word otherwise.
+    PluralForm(TimeBoundary, PluralCategory),
+    /// A sentence [Pattern], such as `past`. Optional; English word order is used when missing.
+    Pattern(Pattern),
+    /// The form of a word for a [Usage], such as `past.days`. Optional; see
+    /// [Translator::translate_in](crate::translator::Translator::translate_in) for how variants
+    /// fall back.
+    Variant(Usage, Box<Words>),
+    /// The [Gender] of a unit or weekday, such as `week_gender: feminine`. Optional; modifiers
+    /// use their plain form for nouns without one.
+    GenderOf(Box<Words>),
+    /// The form of a [Modifier] agreeing with a [Gender], such as `last_feminine`. Optional; the
+    /// plain modifier is used when missing.
+    Agreeing(Modifier, Gender),
+    /// A rule for spelling out numbers from this one up to the next rule, such as
+    /// `cardinal_20: "twenty[->>]"`, optionally for a [Gender] as in `cardinal_1_feminine`.
+    /// Optional; numbers are written in digits without them. See
+    /// [Translator::cardinal](crate::translator::Translator::cardinal).
+    Cardinal(u64, Option<Gender>),
+    /// The indefinite article of a unit, such as `hour_article: an`. Optional; [Words::A] is used
+    /// when missing.
+    Article(Box<Words>),
+    /// The suffix of a number written as an ordinal in digits, for the [PluralCategory] chosen by
+    /// the locale's [OrdinalRules](super::OrdinalRules), such as `suffix_two: nd`, and optionally
+    /// for a [Gender] as in `suffix_other_feminine: ª`. Optional; falls back to the plain suffix
+    /// and then to `suffix_other`, and ordinals are written as bare digits without any.
+    Suffix(PluralCategory, Option<Gender>),
+    /// A rule for spelling out ordinals from this one up to the next rule, such as
+    /// `ordinal_20: "twent[y->>|ieth]"`, optionally for a [Gender]. Optional; ordinals are written
+    /// in digits with their [Words::Suffix] without them. See
+    /// [Translator::ordinal](crate::translator::Translator::ordinal).
+    Ordinal(u64, Option<Gender>),
+    /// The abbreviation of a unit in a short or narrow [ListWidth], such as `hour_short: hr.` or
+    /// `hour_narrow: h`, optionally for a [PluralCategory] as in `year_short_many: л.`. Optional;
+    /// narrow abbreviations fall back to short ones, and short ones to the full [Words::PluralForm].
+    /// See [Translator::abbreviation](crate::translator::Translator::abbreviation).
+    Abbreviation(TimeBoundary, ListWidth, Option<PluralCategory>),
 }
 
 impl Words {
+    /// Yield every word, in the order they are declared.
+    pub fn all() -> Vec<Self> {
+        let mut all = vec![
+            Self::January,
+            Self::February,
+            Self::March,
+            Self::April,
+            Self::May,
+            Self::June,
+            Self::July,
+            Self::August,
+            Self::September,
+            Self::October,
+            Self::November,
+            Self::December,
+        ];
+
+        all.extend([
+            Self::Noon,
+            Self::Midnight,
+            Self::PM,
+            Self::AM,
+            Self::A,
+            Self::In,
+            Self::An,
+            Self::And,
+            Self::FromNow,
+            Self::At,
+            Self::Ago,
+            Self::Last,
+            Self::Next,
+            Self::This,
+            Self::Year,
+            Self::Week,
+            Self::Month,
+            Self::Day,
+            Self::Hour,
+            Self::Minute,
+            Self::Second,
+            Self::YearPlural,
+            Self::WeekPlural,
+            Self::MonthPlural,
+            Self::DayPlural,
+            Self::HourPlural,
+            Self::MinutePlural,
+            Self::SecondPlural,
+            Self::Yesterday,
+            Self::Today,
+            Self::Tomorrow,
+            Self::Sunday,
+            Self::Monday,
+            Self::Tuesday,
+            Self::Wednesday,
+            Self::Thursday,
+            Self::Friday,
+            Self::Saturday,
+            Self::Language,
+            Self::Numbering,
+        ]);
+
+        for boundary in TimeBoundary::all().into_iter().rev() {
+            for category in PluralCategory::all() {
+                all.push(Self::PluralForm(boundary.clone(), category));
+            }
+        }
+
+        all.extend(Pattern::all().into_iter().map(Self::Pattern));
+
+        for boundary in TimeBoundary::all().into_iter().rev() {
+            all.push(Self::GenderOf(Box::new(boundary.into())));
+        }
+
+        for weekday in [
+            Self::Sunday,
+            Self::Monday,
+            Self::Tuesday,
+            Self::Wednesday,
+            Self::Thursday,
+            Self::Friday,
+            Self::Saturday,
+        ] {
+            all.push(Self::GenderOf(Box::new(weekday)));
+        }
+
+        for modifier in Modifier::all() {
+            for gender in Gender::all() {
+                all.push(Self::Agreeing(modifier, gender));
+            }
+        }
+
+        let cardinals = (0..=20)
+            .chain((30..=90).step_by(10))
+            .chain([100, 1000, 1_000_000]);
+        all.extend(cardinals.map(|n| Self::Cardinal(n, None)));
+        for n in [1, 2] {
+            all.extend(
+                Gender::all()
+                    .into_iter()
+                    .map(|gender| Self::Cardinal(n, Some(gender))),
+            );
+        }
+
+        for boundary in TimeBoundary::all().into_iter().rev() {
+            all.push(Self::Article(Box::new(boundary.into())));
+        }
+
+        for category in PluralCategory::all() {
+            all.push(Self::Suffix(category, None));
+            all.extend(
+                Gender::all()
+                    .into_iter()
+                    .map(|gender| Self::Suffix(category, Some(gender))),
+            );
+        }
+
+        for boundary in TimeBoundary::all().into_iter().rev() {
+            for width in [ListWidth::Short, ListWidth::Narrow] {
+                all.push(Self::Abbreviation(boundary.clone(), width, None));
+                all.extend(
+                    PluralCategory::all().into_iter().map(|category| {
+                        Self::Abbreviation(boundary.clone(), width, Some(category))
+                    }),
+                );
+            }
+        }
+
+        let ordinals = (0..=20)
+            .chain((30..=90).step_by(10))
+            .chain([100, 1000, 1_000_000]);
+        all.extend(ordinals.map(|n| Self::Ordinal(n, None)));
+        all.extend(
+            Gender::all()
+                .into_iter()
+                .map(|gender| Self::Ordinal(1, Some(gender))),
+        );
+
+        let words: Vec<Self> = all
+            .iter()
+            .filter(|word| {
+                !matches!(
+                    word,
+                    Self::Language | Self::Numbering | Self::Pattern(_) | Self::GenderOf(_)
+                )
+            })
+            .cloned()
+            .collect();
+        for usage in Usage::all() {
+            all.extend(
+                words
+                    .iter()
+                    .map(|word| Self::Variant(usage, Box::new(word.clone()))),
+            );
+        }
+
+        all
+    }
+
     /// For a given word, make it plural if possible. Otherwise just return the same word.
     pub fn plural(&self) -> Self {
         match self {
@@ -70,6 +275,120 @@ impl Words {
             _ => self.clone(),
         }
     }
+
+    /// The word to use when this one has no translation, if any. Words with a fallback need not
+    /// appear in a locale.
+    pub fn fallback(&self) -> Option<Self> {
+        match self {
+            Self::PluralForm(boundary, PluralCategory::One) => Some(boundary.clone().into()),
+            Self::PluralForm(boundary, _) => Some(Words::from(boundary.clone()).plural()),
+            Self::Pattern(Pattern::UnitQuantity(_)) => Some(Self::Pattern(Pattern::Quantity)),
+            Self::Pattern(Pattern::UnitOrdinal(_)) => Some(Self::Pattern(Pattern::Ordinal)),
+            Self::Pattern(Pattern::AbbreviatedQuantity(width)) => {
+                Some(Self::Pattern(match width {
+                    ListWidth::Narrow => Pattern::AbbreviatedQuantity(ListWidth::Short),
+                    _ => Pattern::Quantity,
+                }))
+            }
+            Self::Pattern(Pattern::PluralQuantity(width, category)) => {
+                Some(Self::Pattern(Pattern::PluralQuantity(
+                    match width {
+                        ListWidth::Narrow => ListWidth::Short,
+                        ListWidth::Short => ListWidth::Wide,
+                        ListWidth::Wide => return None,
+                    },
+                    *category,
+                )))
+            }
+            Self::Pattern(Pattern::List(style, part)) => {
+                Some(Self::Pattern(Pattern::List(style.wider()?, *part)))
+            }
+            Self::Variant(_, word) => Some(*word.clone()),
+            Self::Agreeing(modifier, _) => Some((*modifier).into()),
+            Self::Cardinal(n, Some(_)) => Some(Self::Cardinal(*n, None)),
+            Self::Ordinal(n, Some(_)) => Some(Self::Ordinal(*n, None)),
+            Self::Suffix(category, Some(_)) => Some(Self::Suffix(*category, None)),
+            Self::Abbreviation(boundary, width, Some(_)) => {
+                Some(Self::Abbreviation(boundary.clone(), *width, None))
+            }
+            Self::Abbreviation(boundary, ListWidth::Narrow, None) => {
+                Some(Self::Abbreviation(boundary.clone(), ListWidth::Short, None))
+            }
+            Self::Suffix(category, None) if *category != PluralCategory::Other => {
+                Some(Self::Suffix(PluralCategory::Other, None))
+            }
+            Self::Article(_) => Some(Self::A),
+            _ => None,
+        }
+    }
+
+    /// Is this word a noun: a unit, month, weekday, noon or midnight, in any form?
+    pub fn is_noun(&self) -> bool {
+        match self {
+            Self::Noon
+            | Self::Midnight
+            | Self::Year
+            | Self::Week
+            | Self::Month
+            | Self::Day
+            | Self::Hour
+            | Self::Minute
+            | Self::Second
+            | Self::YearPlural
+            | Self::WeekPlural
+            | Self::MonthPlural
+            | Self::DayPlural
+            | Self::HourPlural
+            | Self::MinutePlural
+            | Self::SecondPlural
+            | Self::PluralForm(..)
+            | Self::Abbreviation(..) => true,
+            Self::Variant(_, word) => word.is_noun(),
+            word => word.is_proper_noun(),
+        }
+    }
+
+    /// Is this word the name of a month or weekday?
+    pub fn is_proper_noun(&self) -> bool {
+        match self {
+            Self::January
+            | Self::February
+            | Self::March
+            | Self::April
+            | Self::May
+            | Self::June
+            | Self::July
+            | Self::August
+            | Self::September
+            | Self::October
+            | Self::November
+            | Self::December
+            | Self::Sunday
+            | Self::Monday
+            | Self::Tuesday
+            | Self::Wednesday
+            | Self::Thursday
+            | Self::Friday
+            | Self::Saturday => true,
+            Self::Variant(_, word) => word.is_proper_noun(),
+            _ => false,
+        }
+    }
+
+    /// Can this word be left out of a locale?
+    pub fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            Self::Language
+                | Self::Numbering
+                | Self::Pattern(_)
+                | Self::GenderOf(_)
+                | Self::Cardinal(..)
+                | Self::Ordinal(..)
+                | Self::Suffix(..)
+                | Self::Abbreviation(..)
+        ) || self.fallback().is_some()
+    }
 }
 
 impl std::fmt::Display for Words {
@@ -87,7 +406,6 @@ impl std::fmt::Display for Words {
             Words::October => "october".to_string(),
             Words::November => "november".to_string(),
             Words::December => "december".to_string(),
-            Words::Suffix(suffix) => format!("suffix_{}", suffix),
             Words::Noon => "noon".to_string(),
             Words::Midnight => "midnight".to_string(),
             Words::PM => "pm".to_string(),
@@ -100,6 +418,8 @@ impl std::fmt::Display for Words {
             Words::At => "at".to_string(),
             Words::Ago => "ago".to_string(),
             Words::Last => "last".to_string(),
+            Words::Next => "next".to_string(),
+            Words::This => "this".to_string(),
             Words::Year => "year".to_string(),
             Words::Week => "week".to_string(),
             Words::Month => "month".to_string(),
@@ -124,6 +444,35 @@ impl std::fmt::Display for Words {
             Words::Thursday => "thursday".to_string(),
             Words::Friday => "friday".to_string(),
             Words::Saturday => "saturday".to_string(),
+            Words::Language => "language".to_string(),
+            Words::Numbering => "numbering".to_string(),
+            Words::PluralForm(boundary, category) => {
+                format!("{}_{}", Words::from(boundary.clone()), category)
+            }
+            Words::Pattern(pattern) => pattern.to_string(),
+            Words::Variant(usage, word) => format!("{}.{}", usage, word),
+            Words::GenderOf(word) => format!("{}_gender", word),
+            Words::Agreeing(modifier, gender) => format!("{}_{}", Words::from(*modifier), gender),
+            Words::Cardinal(n, None) => format!("cardinal_{}", n),
+            Words::Cardinal(n, Some(gender)) => format!("cardinal_{}_{}", n, gender),
+            Words::Article(word) => format!("{}_article", word),
+            Words::Suffix(category, None) => format!("suffix_{}", category),
+            Words::Suffix(category, Some(gender)) => format!("suffix_{}_{}", category, gender),
+            Words::Ordinal(n, None) => format!("ordinal_{}", n),
+            Words::Ordinal(n, Some(gender)) => format!("ordinal_{}_{}", n, gender),
+            Words::Abbreviation(boundary, width, category) => {
+                let width = match width {
+                    ListWidth::Narrow => "narrow",
+                    _ => "short",
+                };
+
+                match category {
+                    Some(category) => {
+                        format!("{}_{}_{}", Words::from(boundary.clone()), width, category)
+                    }
+                    None => format!("{}_{}", Words::from(boundary.clone()), width),
+                }
+            }
         };
 
         f.write_str(&s)
@@ -147,16 +496,6 @@ impl std::str::FromStr for Words {
             "october" => Words::October,
             "november" => Words::November,
             "december" => Words::December,
-            "suffix_0" => Words::Suffix(0),
-            "suffix_1" => Words::Suffix(1),
-            "suffix_2" => Words::Suffix(2),
-            "suffix_3" => Words::Suffix(3),
-            "suffix_4" => Words::Suffix(4),
-            "suffix_5" => Words::Suffix(5),
-            "suffix_6" => Words::Suffix(6),
-            "suffix_7" => Words::Suffix(7),
-            "suffix_8" => Words::Suffix(8),
-            "suffix_9" => Words::Suffix(9),
             "noon" => Words::Noon,
             "midnight" => Words::Midnight,
             "pm" => Words::PM,
@@ -169,6 +508,8 @@ impl std::str::FromStr for Words {
             "at" => Words::At,
             "ago" => Words::Ago,
             "last" => Words::Last,
+            "next" => Words::Next,
+            "this" => Words::This,
             "year" => Words::Year,
             "week" => Words::Week,
             "month" => Words::Month,
@@ -193,7 +534,96 @@ impl std::str::FromStr for Words {
             "thursday" => Words::Thursday,
             "friday" => Words::Friday,
             "saturday" => Words::Saturday,
-            x => return Err(anyhow::anyhow!("invalid word '{}'", x)),
+            "language" => Words::Language,
+            "numbering" => Words::Numbering,
+            x => {
+                let form = x.split_once('_').and_then(|(word, category)| {
+                    let boundary: Option<TimeBoundary> = Words::from_str(word).ok()?.into();
+                    Some(Words::PluralForm(boundary?, category.parse().ok()?))
+                });
+
+                let variant = || {
+                    let (usage, word) = x.split_once('.')?;
+                    match Words::from_str(word).ok()? {
+                        Words::Language
+                        | Words::Numbering
+                        | Words::Pattern(_)
+                        | Words::Variant(..) => None,
+                        word => Some(Words::Variant(usage.parse().ok()?, Box::new(word))),
+                    }
+                };
+
+                let cardinal = || {
+                    let rest = x.strip_prefix("cardinal_")?;
+                    Some(match rest.split_once('_') {
+                        Some((n, gender)) => {
+                            Words::Cardinal(n.parse().ok()?, Some(gender.parse().ok()?))
+                        }
+                        None => Words::Cardinal(rest.parse().ok()?, None),
+                    })
+                };
+
+                let ordinal = || {
+                    if let Some(rest) = x.strip_prefix("suffix_") {
+                        return Some(match rest.split_once('_') {
+                            Some((category, gender)) => {
+                                Words::Suffix(category.parse().ok()?, Some(gender.parse().ok()?))
+                            }
+                            None => Words::Suffix(rest.parse().ok()?, None),
+                        });
+                    }
+
+                    let rest = x.strip_prefix("ordinal_")?;
+                    Some(match rest.split_once('_') {
+                        Some((n, gender)) => {
+                            Words::Ordinal(n.parse().ok()?, Some(gender.parse().ok()?))
+                        }
+                        None => Words::Ordinal(rest.parse().ok()?, None),
+                    })
+                };
+
+                let abbreviation = || {
+                    let (word, rest) = x.split_once('_')?;
+                    let boundary: Option<TimeBoundary> = Words::from_str(word).ok()?.into();
+                    let (width, category) = match rest.split_once('_') {
+                        Some((width, category)) => (width, Some(category.parse().ok()?)),
+                        None => (rest, None),
+                    };
+
+                    let width = match width {
+                        "short" => ListWidth::Short,
+                        "narrow" => ListWidth::Narrow,
+                        _ => return None,
+                    };
+
+                    Some(Words::Abbreviation(boundary?, width, category))
+                };
+
+                let article = || {
+                    let word = x.strip_suffix("_article")?;
+                    Some(Words::Article(Box::new(Words::from_str(word).ok()?)))
+                };
+
+                let gender = || {
+                    if let Some(word) = x.strip_suffix("_gender") {
+                        return Some(Words::GenderOf(Box::new(Words::from_str(word).ok()?)));
+                    }
+
+                    let (modifier, gender) = x.split_once('_')?;
+                    let modifier: Option<Modifier> = Words::from_str(modifier).ok()?.into();
+                    Some(Words::Agreeing(modifier?, gender.parse().ok()?))
+                };
+
+                return form
+                    .or_else(|| x.parse().ok().map(Words::Pattern))
+                    .or_else(variant)
+                    .or_else(cardinal)
+                    .or_else(ordinal)
+                    .or_else(abbreviation)
+                    .or_else(article)
+                    .or_else(gender)
+                    .ok_or_else(|| anyhow::anyhow!("invalid word '{}'", x));
+            }
         })
     }
 }
@@ -241,3 +671,22 @@ mod serde {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_all_round_trip() {
+        use super::Words;
+        use std::str::FromStr;
+
+        let all = Words::all();
+        for word in &all {
+            assert_eq!(word, &Words::from_str(&word.to_string()).unwrap());
+        }
+
+        let mut sorted = all.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(all.len(), sorted.len());
+    }
+}
@@ -5,8 +5,8 @@
 //! To accomplish this, we seed an [Approximator] with a list of selectors called
 //! [ApproximateFilter]s, and a
 //! [FormatGenerator](crate::approximate::format_generator::FormatGenerator), of which there are
-//! currently two styles: [CoarseRoundFormat] and [FancyDurationFormat]. The approximator computes
-//! durations into tokenized parts (called [ApproximateState](crate::approximate::ApproximateState)) which are picked out by the
+//! several styles: [CoarseRoundFormat], [FancyDurationFormat], [Iso8601Format],
+//! [GoDurationFormat] and [SystemdTimespanFormat]. The approximator computes durations into tokenized parts (called [ApproximateState](crate::approximate::ApproximateState)) which are picked out by the
 //! [ApproximateFilter]s, which are then fed to the formatter of choice to generate a syntax. Then,
 //! a [Translator] is used to convert that final syntax into something you can read. It is fully
 //! localized and flexible.
@@ -80,17 +80,30 @@ pub mod time_boundary;
 pub mod translator;
 
 pub use crate::{
-    approximate::{ApproximateFilter, Approximator, CoarseRoundFormat, FancyDurationFormat},
-    enums::Words,
+    approximate::{
+        ApproximateFilter, Approximator, CoarseRoundFormat, FancyDurationFormat, GoDurationFormat,
+        Iso8601Format, Iso8601Sign, SystemdTimespanFormat,
+    },
+    enums::{
+        Capitalization, CasingRules, Gender, ListKind, ListPart, ListStyle, ListWidth, Modifier,
+        NumberingSystem, OrdinalRules, Pattern, PluralCategory, PluralRules, Usage, Words,
+    },
     time_boundary::TimeBoundary,
-    translator::{TranslationMap, Translator, DEFAULT_TRANSLATION},
+    translator::{
+        Argument, Arguments, Message, MessageError, NumberForm, OwnedTranslationMap, Template,
+        Token, Translate, TranslationError, TranslationMap, Translator, DEFAULT_TRANSLATION,
+    },
 };
 
+#[doc(hidden)]
+pub use anyhow;
+
 /// Use this to import major chunks of functionality from speakable_time.
 pub mod prelude {
     pub use crate::{
         approximator, from_now, time_diff, translation_map, translator, ApproximateFilter,
-        CoarseRoundFormat, FancyDurationFormat, TimeBoundary, Words,
+        CoarseRoundFormat, FancyDurationFormat, GoDurationFormat, Iso8601Format, Iso8601Sign,
+        SystemdTimespanFormat, TimeBoundary, Words,
     };
 }
 
@@ -111,7 +124,7 @@ macro_rules! approximator {
 macro_rules! translator {
     ($(($key:expr, $value:expr)),*) => {{
         use $crate::translator::Translator;
-        Translator::new(map!($(($key, $value),)*))
+        Translator::new($crate::translation_map!($(($key, $value)),*))
     }}
 }
 
@@ -120,11 +133,17 @@ macro_rules! translator {
 #[macro_export]
 macro_rules! from_now {
     ($dt:expr, $approx:expr, $translation:expr) => {{
-        $translation.format(&$approx.from_now($dt).to_string())
+        Ok::<String, $crate::anyhow::Error>($crate::translator::Translate::render(
+            &$translation,
+            &$approx.from_now($dt).into_template(),
+        ))
     }};
     ($dt:expr, $approx:expr) => {{
         use $crate::translator::DEFAULT_TRANSLATION;
-        DEFAULT_TRANSLATION.format(&$approx.from_now($dt).to_string())
+        Ok::<String, $crate::anyhow::Error>($crate::translator::Translate::render(
+            &DEFAULT_TRANSLATION,
+            &$approx.from_now($dt).into_template(),
+        ))
     }};
 }
 
@@ -133,11 +152,17 @@ macro_rules! from_now {
 #[macro_export]
 macro_rules! time_diff {
     ($from:expr, $to:expr, $approx:expr, $translation:expr) => {{
-        $translation.format(&$approx.difference($from, $to).to_string())
+        Ok::<String, $crate::anyhow::Error>($crate::translator::Translate::render(
+            &$translation,
+            &$approx.difference($from, $to).into_template(),
+        ))
     }};
     ($from:expr, $to:expr, $approx:expr) => {{
         use $crate::translator::DEFAULT_TRANSLATION;
-        DEFAULT_TRANSLATION.format(&$approx.difference($from, $to).to_string())
+        Ok::<String, $crate::anyhow::Error>($crate::translator::Translate::render(
+            &DEFAULT_TRANSLATION,
+            &$approx.difference($from, $to).into_template(),
+        ))
     }};
 }
 
@@ -15,30 +15,30 @@ pub enum TimeBoundary {
     Year,
 }
 
-impl Into<Words> for TimeBoundary {
-    fn into(self) -> Words {
-        match self {
-            Self::Second => Words::Second,
-            Self::Minute => Words::Minute,
-            Self::Hour => Words::Hour,
-            Self::Day => Words::Day,
-            Self::Week => Words::Week,
-            Self::Month => Words::Month,
-            Self::Year => Words::Year,
+impl From<TimeBoundary> for Words {
+    fn from(value: TimeBoundary) -> Self {
+        match value {
+            TimeBoundary::Second => Words::Second,
+            TimeBoundary::Minute => Words::Minute,
+            TimeBoundary::Hour => Words::Hour,
+            TimeBoundary::Day => Words::Day,
+            TimeBoundary::Week => Words::Week,
+            TimeBoundary::Month => Words::Month,
+            TimeBoundary::Year => Words::Year,
         }
     }
 }
 
-impl Into<Option<TimeBoundary>> for Words {
-    fn into(self) -> Option<TimeBoundary> {
-        match self {
-            Self::Second => Some(TimeBoundary::Second),
-            Self::Minute => Some(TimeBoundary::Minute),
-            Self::Hour => Some(TimeBoundary::Hour),
-            Self::Day => Some(TimeBoundary::Day),
-            Self::Week => Some(TimeBoundary::Week),
-            Self::Month => Some(TimeBoundary::Month),
-            Self::Year => Some(TimeBoundary::Year),
+impl From<Words> for Option<TimeBoundary> {
+    fn from(value: Words) -> Self {
+        match value {
+            Words::Second => Some(TimeBoundary::Second),
+            Words::Minute => Some(TimeBoundary::Minute),
+            Words::Hour => Some(TimeBoundary::Hour),
+            Words::Day => Some(TimeBoundary::Day),
+            Words::Week => Some(TimeBoundary::Week),
+            Words::Month => Some(TimeBoundary::Month),
+            Words::Year => Some(TimeBoundary::Year),
             _ => None,
         }
     }
@@ -61,13 +61,29 @@ impl TimeBoundary {
     /// Yield the highest boundary the difference between these two times can report
     #[inline]
     pub fn highest(dt: DateTime<Local>, dt2: DateTime<Local>) -> Option<Self> {
-        for item in Self::all() {
-            if item.within(dt, dt2) {
-                return Some(item);
-            }
-        }
+        Self::all().into_iter().find(|item| item.within(dt, dt2))
+    }
+
+    /// Convert a count of this boundary into a [chrono::Duration]. Months are treated as 30 days
+    /// and years as 365 days, the same as the
+    /// [Approximator](crate::approximate::Approximator) does when it rounds. [None] is returned if
+    /// the duration is out of range.
+    #[inline]
+    pub fn duration(&self, count: i64) -> Option<chrono::Duration> {
+        let seconds = count.checked_mul(match self {
+            Self::Second => 1,
+            Self::Minute => 60,
+            Self::Hour => 60 * 60,
+            Self::Day => 24 * 60 * 60,
+            Self::Week => 7 * 24 * 60 * 60,
+            Self::Month => 30 * 24 * 60 * 60,
+            Self::Year => 365 * 24 * 60 * 60,
+        })?;
 
-        None
+        let limit = chrono::Duration::max_value().num_seconds();
+        (-limit..=limit)
+            .contains(&seconds)
+            .then(|| chrono::Duration::seconds(seconds))
     }
 
     /// Get the specific numeric value for the current boundary.
@@ -0,0 +1,146 @@
+use super::TranslationMap;
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+use super::Translator;
+use crate::enums::{PluralCategory, PluralRules, Words};
+use crate::time_boundary::TimeBoundary;
+use std::str::FromStr;
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+use std::sync::OnceLock;
+
+/// A source of translations keyed by message IDs, such as a Fluent or gettext catalog delivered by
+/// a localization vendor. A [Translator](super::Translator) can be built from any catalog with
+/// [Translator::from_catalog](super::Translator::from_catalog).
+///
+/// Message IDs name [Words] as the keys of the locale files in `st-locales` do, such as `days`,
+/// `past.days` or `quantity`; [Catalog::word] can map them differently. Plurals are left to the
+/// catalog: each [PluralCategory] of a unit is filled with the form [Catalog::plural] gives for a
+/// number in that category.
+///
+/// The catalogs of this crate also implement [Translate](super::Translate) themselves.
+pub trait Catalog {
+    /// The language of the catalog, from its metadata, such as `ru`. A `language` message takes
+    /// precedence.
+    fn language(&self) -> Option<&str>;
+
+    /// Iterate over the IDs of every message in the catalog.
+    fn ids(&self) -> Box<dyn Iterator<Item = &str> + '_>;
+
+    /// The translation of a message, if the catalog has it.
+    fn message(&self, id: &str) -> Option<&str>;
+
+    /// The form of a message for a number, chosen by the catalog's own plural rules. [None] is
+    /// returned if the message has no plural forms.
+    fn plural(&self, id: &str, count: i64) -> Option<&str>;
+
+    /// The word a message ID translates, if any. IDs are parsed as locale keys by default.
+    fn word(&self, id: &str) -> Option<Words> {
+        Words::from_str(id).ok()
+    }
+
+    /// Build a map of every word the catalog translates. Plural messages of a unit, such as
+    /// `days`, fill the [Words::PluralForm]s of the unit for the plural rules of the language,
+    /// unless the catalog translates those forms directly.
+    fn translation_map(&self) -> TranslationMap<'_> {
+        let mut map = TranslationMap::new();
+        let mut plurals = Vec::new();
+
+        for id in self.ids() {
+            let Some(word) = self.word(id) else {
+                continue;
+            };
+
+            if let Some(value) = self.message(id) {
+                map.insert(word.clone(), value);
+            }
+
+            plurals.push((id, word));
+        }
+
+        if let Some(language) = self.language() {
+            map.entry(Words::Language).or_insert(language);
+        }
+
+        let rules = map
+            .get(&Words::Language)
+            .map(|language| PluralRules::for_language(language))
+            .unwrap_or_default();
+
+        for (id, word) in plurals {
+            for category in PluralCategory::all() {
+                let Some(form) = plural_form(&word, category) else {
+                    break;
+                };
+
+                let Some(value) = rules
+                    .sample(category)
+                    .and_then(|count| self.plural(id, count))
+                else {
+                    continue;
+                };
+
+                map.entry(form).or_insert(value);
+            }
+        }
+
+        map
+    }
+}
+
+/// The [Translator] a catalog translates with, built from it the first time it is needed. It is
+/// not part of the catalog's value: a clone starts empty, and it never makes catalogs unequal.
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+#[derive(Default)]
+pub(crate) struct CatalogTranslator(OnceLock<Translator<'static>>);
+
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+impl CatalogTranslator {
+    pub(crate) fn get(&self, catalog: &impl Catalog) -> &Translator<'static> {
+        self.0
+            .get_or_init(|| Translator::from_catalog(catalog).into_owned())
+    }
+}
+
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+impl Clone for CatalogTranslator {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+impl std::fmt::Debug for CatalogTranslator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CatalogTranslator")
+    }
+}
+
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+impl PartialEq for CatalogTranslator {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(any(feature = "fluent", feature = "gettext"))]
+impl Eq for CatalogTranslator {}
+
+/// The word for a form of a plural word in a category: `day_few` for `days`, and `year_short_many`
+/// for `year_short`. [None] is returned for words without plural forms.
+fn plural_form(word: &Words, category: PluralCategory) -> Option<Words> {
+    match word {
+        Words::Variant(usage, word) => Some(Words::Variant(
+            *usage,
+            Box::new(plural_form(word, category)?),
+        )),
+        Words::Abbreviation(unit, width, None) => {
+            Some(Words::Abbreviation(unit.clone(), *width, Some(category)))
+        }
+        word => TimeBoundary::all()
+            .into_iter()
+            .find(|unit| {
+                let noun = Words::from(unit.clone());
+                noun == *word || noun.plural() == *word
+            })
+            .map(|unit| Words::PluralForm(unit, category)),
+    }
+}
@@ -0,0 +1,260 @@
+use crate::translator::{locale_chain, TranslationMap, Translator};
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex, RwLock, RwLockReadGuard,
+};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Locale files read from a directory at runtime, as opposed to the [LOCALES](super::LOCALES)
+/// embedded at compile time. Files are named as they are in `st-locales`, such as `C.yml`,
+/// `en.yml` or `en-GB.yml`, and translators are layered the same way as
+/// [load_locale](crate::load_locale): see [locale_chain].
+///
+/// Every file is parsed when it is read, so a directory which loads holds only valid locales.
+/// Translators borrow from the directory, unless copied with [Translator::into_owned]; call
+/// [LocaleDirectory::reload] or [LocaleDirectory::watch] to pick up changes.
+#[derive(Clone, Debug)]
+pub struct LocaleDirectory {
+    path: PathBuf,
+    files: BTreeMap<String, String>,
+}
+
+impl LocaleDirectory {
+    /// Read every `.yml` file in the directory.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let files = Self::read(&path)?;
+        Ok(Self { path, files })
+    }
+
+    /// The directory the locales are read from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The names of the locales found, such as `en-GB`.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(|x| x.as_str())
+    }
+
+    /// Build a layered [Translator] for a locale. An error is returned if neither the locale, its
+    /// language nor `C` is present.
+    pub fn translator(&self, locale: &str) -> Result<Translator<'_>> {
+        let mut maps = Vec::new();
+        for name in locale_chain(locale) {
+            if let Some(contents) = self.files.get(&name) {
+                maps.push(Self::parse(&name, contents)?);
+            }
+        }
+
+        Translator::layered(maps).ok_or_else(|| {
+            anyhow!(
+                "No translations for {} in {}",
+                locale,
+                self.path.to_string_lossy()
+            )
+        })
+    }
+
+    /// Read the directory again, returning whether anything changed. If any file fails to read or
+    /// parse, an error is returned and the previous contents are kept.
+    pub fn reload(&mut self) -> Result<bool> {
+        let files = Self::read(&self.path)?;
+        if files == self.files {
+            return Ok(false);
+        }
+
+        self.files = files;
+        Ok(true)
+    }
+
+    /// Move the directory to a background thread which calls [LocaleDirectory::reload] every
+    /// `interval`, until the returned [LocaleWatcher] is dropped.
+    pub fn watch(self, interval: Duration) -> LocaleWatcher {
+        let directory = Arc::new(RwLock::new(self));
+        let stop = Arc::new(AtomicBool::new(false));
+        let generation = Arc::new(AtomicU64::new(0));
+        let error = Arc::new(Mutex::new(None));
+
+        let handle = {
+            let directory = directory.clone();
+            let stop = stop.clone();
+            let generation = generation.clone();
+            let error = error.clone();
+
+            std::thread::spawn(move || {
+                while !stop.load(Ordering::Acquire) {
+                    std::thread::park_timeout(interval);
+                    if stop.load(Ordering::Acquire) {
+                        break;
+                    }
+
+                    let path = directory.read().unwrap().path.clone();
+                    let result = Self::read(&path).map(|files| {
+                        let mut directory = directory.write().unwrap();
+                        if files != directory.files {
+                            directory.files = files;
+                            generation.fetch_add(1, Ordering::AcqRel);
+                        }
+                    });
+
+                    *error.lock().unwrap() = result.err().map(|e| e.to_string());
+                }
+            })
+        };
+
+        LocaleWatcher {
+            directory,
+            stop,
+            generation,
+            error,
+            handle: Some(handle),
+        }
+    }
+
+    fn parse<'a>(name: &str, contents: &'a str) -> Result<TranslationMap<'a>> {
+        serde_yaml::from_str(contents).map_err(|e| anyhow!("{}.yml: {}", name, e))
+    }
+
+    fn read(path: &Path) -> Result<BTreeMap<String, String>> {
+        let mut files = BTreeMap::new();
+
+        for entry in
+            std::fs::read_dir(path).map_err(|e| anyhow!("{}: {}", path.to_string_lossy(), e))?
+        {
+            let file = entry?.path();
+            if file.extension().and_then(|x| x.to_str()) != Some("yml") || !file.is_file() {
+                continue;
+            }
+
+            let name = match file.file_stem().and_then(|x| x.to_str()) {
+                Some(name) => name.to_string(),
+                None => continue,
+            };
+
+            let contents = std::fs::read_to_string(&file)
+                .map_err(|e| anyhow!("{}: {}", file.to_string_lossy(), e))?;
+            Self::parse(&name, &contents)?;
+            files.insert(name, contents);
+        }
+
+        Ok(files)
+    }
+}
+
+/// A [LocaleDirectory] which is reloaded in the background; see [LocaleDirectory::watch]. The
+/// thread is stopped when the watcher is dropped.
+#[derive(Debug)]
+pub struct LocaleWatcher {
+    directory: Arc<RwLock<LocaleDirectory>>,
+    stop: Arc<AtomicBool>,
+    generation: Arc<AtomicU64>,
+    error: Arc<Mutex<Option<String>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl LocaleWatcher {
+    /// Borrow the current contents of the directory, to build translators from. Reloads wait
+    /// until the guard is dropped, so do not hold it longer than needed: keep a translator made
+    /// with [Translator::into_owned] instead.
+    pub fn directory(&self) -> RwLockReadGuard<'_, LocaleDirectory> {
+        self.directory.read().unwrap()
+    }
+
+    /// A counter which increases each time a change is loaded. Compare it against an earlier value
+    /// to decide whether cached translators or output need rebuilding.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// The error from the most recent reload, if it failed. The previous contents remain in use.
+    pub fn error(&self) -> Option<String> {
+        self.error.lock().unwrap().clone()
+    }
+}
+
+impl Drop for LocaleWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_locale_directory() {
+        use super::*;
+        use crate::enums::Words;
+
+        let path = std::env::temp_dir().join(format!("speakable-time-{}", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(path.join("C.yml"), "day: day\ndays: days\nago: ago\n").unwrap();
+        std::fs::write(path.join("fr.yml"), "day: jour\ndays: jours\n").unwrap();
+        std::fs::write(path.join("fr-CA.yml"), "days: journées\n").unwrap();
+        std::fs::write(path.join("README"), "not a locale").unwrap();
+
+        let mut directory = LocaleDirectory::open(&path).unwrap();
+        assert_eq!(
+            vec!["C", "fr", "fr-CA"],
+            directory.locales().collect::<Vec<_>>()
+        );
+
+        let translator = directory.translator("fr-CA").unwrap();
+        assert_eq!(
+            "jour journées ago",
+            translator.format("%{day} %{days} %{ago}").unwrap()
+        );
+        let translator = directory.translator("de").unwrap();
+        assert_eq!(Some("days"), translator.translate(&Words::DayPlural));
+
+        assert!(!directory.reload().unwrap());
+        std::fs::write(path.join("de.yml"), "days: Tage\n").unwrap();
+        assert!(directory.reload().unwrap());
+        let translator = directory.translator("de").unwrap();
+        assert_eq!(Some("Tage"), translator.translate(&Words::DayPlural));
+
+        std::fs::write(path.join("de.yml"), "days: [Tage]\n").unwrap();
+        assert!(directory.reload().is_err());
+        let translator = directory.translator("de").unwrap();
+        assert_eq!(Some("Tage"), translator.translate(&Words::DayPlural));
+        assert!(LocaleDirectory::open(&path).is_err());
+        std::fs::write(path.join("de.yml"), "days: Tage\n").unwrap();
+
+        // the watcher may read at any moment, so the file is replaced whole rather than rewritten.
+        let watcher = directory.watch(Duration::from_millis(10));
+        std::fs::write(path.join("de.yml.tmp"), "days: Tagen\n").unwrap();
+        std::fs::rename(path.join("de.yml.tmp"), path.join("de.yml")).unwrap();
+        for _ in 0..500 {
+            if watcher.generation() > 0 {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert!(watcher.generation() >= 1);
+        assert_eq!(None, watcher.error());
+        let translator = watcher.directory().translator("de").unwrap().into_owned();
+        drop(watcher);
+        assert_eq!(Some("Tagen"), translator.translate(&Words::DayPlural));
+        assert_eq!(Some("ago"), translator.translate(&Words::Ago));
+
+        std::fs::remove_dir_all(&path).unwrap();
+        assert!(LocaleDirectory::open(&path).is_err());
+
+        let path = std::env::temp_dir().join(format!("speakable-time-{}-x", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        assert!(LocaleDirectory::open(&path)
+            .unwrap()
+            .translator("fr")
+            .is_err());
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+}
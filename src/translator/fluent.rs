@@ -0,0 +1,566 @@
+use super::catalog::CatalogTranslator;
+use super::{Catalog, Template, Translate};
+use crate::enums::{PluralRules, Words};
+use anyhow::{anyhow, Result};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A Fluent (`.ftl`) catalog, read from a local file or a string, for building a
+/// [Translator](super::Translator) with
+/// [Translator::from_catalog](super::Translator::from_catalog).
+///
+/// Message IDs are locale keys with `-` in place of `.`, as Fluent does not allow dots in IDs:
+/// `past-days` translates `past.days`. Variables are slots, so `{ $count } { $unit }` is the
+/// pattern `%{count} %{unit}`, and message references are words: `{ days }` is `%{days}`. Terms
+/// such as `-brand` are inlined where they are referenced.
+///
+/// Plurals are written as a select expression on any variable, whose variants are keyed by
+/// [PluralCategory](crate::enums::PluralCategory) names or exact numbers:
+///
+/// ```ftl
+/// days = { $count ->
+///     [one] день
+///     [few] дня
+///    *[many] дней
+/// }
+/// ```
+///
+/// This is a subset of Fluent, parsed by this crate rather than a Fluent runtime, which covers
+/// what locale keys need. Anything outside it is an error rather than being misread:
+///
+/// - A message may hold one select expression, and its variants may not hold another.
+/// - Only variables can be selected on, and variants are chosen by this crate's
+///   [PluralRules], as `NUMBER` and other functions are not supported.
+/// - Terms are inlined as text, and cannot take arguments.
+/// - Attributes such as `.title` are skipped, and cannot be referenced.
+///
+/// The catalog implements [Translate] with a [Translator](super::Translator) built from it the
+/// first time a word is translated or a template rendered, and kept until its language is changed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FluentCatalog {
+    language: Option<String>,
+    messages: BTreeMap<String, FluentMessage>,
+    translator: CatalogTranslator,
+}
+
+/// A parsed message: its default value, and every variant of its select expression in full.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct FluentMessage {
+    value: String,
+    variants: Vec<(String, String)>,
+}
+
+impl FluentCatalog {
+    /// Read a catalog from a file. The language is taken from the name of the file, such as
+    /// `ru.ftl`, unless the catalog has a `language` message.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("{}: {}", path.to_string_lossy(), e))?;
+
+        let mut catalog =
+            Self::from_str(&contents).map_err(|e| anyhow!("{}: {}", path.to_string_lossy(), e))?;
+        catalog.language = path
+            .file_stem()
+            .and_then(|x| x.to_str())
+            .map(|x| x.to_string());
+        Ok(catalog)
+    }
+
+    /// Set the language of the catalog, which picks the plural variant of a number, unless the
+    /// catalog has a `language` message.
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self.translator = CatalogTranslator::default();
+        self
+    }
+}
+
+impl FromStr for FluentCatalog {
+    type Err = anyhow::Error;
+
+    /// Parse the contents of a `.ftl` file. Errors give the line of the offending entry.
+    fn from_str(source: &str) -> Result<Self> {
+        let mut terms = BTreeMap::new();
+        let mut messages = BTreeMap::new();
+
+        for (line, id, source) in entries(source)? {
+            let message = parse_message(&source, &terms)
+                .map_err(|e| anyhow!("line {}: {}: {}", line, id, e))?;
+
+            match id.strip_prefix('-') {
+                Some(term) => {
+                    terms.insert(term.to_string(), message.value);
+                }
+                None => {
+                    messages.insert(id, message);
+                }
+            }
+        }
+
+        Ok(Self {
+            language: None,
+            messages,
+            translator: CatalogTranslator::default(),
+        })
+    }
+}
+
+impl Catalog for FluentCatalog {
+    fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.messages.keys().map(|x| x.as_str()))
+    }
+
+    fn message(&self, id: &str) -> Option<&str> {
+        Some(self.messages.get(id)?.value.as_str())
+    }
+
+    fn plural(&self, id: &str, count: i64) -> Option<&str> {
+        let message = self.messages.get(id)?;
+        if message.variants.is_empty() {
+            return None;
+        }
+
+        let category = self
+            .message("language")
+            .or(self.language())
+            .map(PluralRules::for_language)
+            .unwrap_or_default()
+            .category(count)
+            .to_string();
+
+        let variant = |key: &str| message.variants.iter().find(|(variant, _)| variant == key);
+
+        match variant(&count.to_string()).or_else(|| variant(&category)) {
+            Some((_, value)) => Some(value),
+            None => Some(&message.value),
+        }
+    }
+
+    fn word(&self, id: &str) -> Option<Words> {
+        Words::from_str(&id.replace('-', ".")).ok()
+    }
+}
+
+impl Translate for FluentCatalog {
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+        Translate::translate(self.translator.get(self), word)
+    }
+
+    fn render_into(&self, template: &Template, s: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        Translate::render_into(self.translator.get(self), template, s)
+    }
+}
+
+/// Split a catalog into its entries: the line each starts on, its ID, and the source of its
+/// value with continuation lines joined. Comments and attributes are left out.
+fn entries(source: &str) -> Result<Vec<(usize, String, String)>> {
+    let mut entries: Vec<(usize, String, String)> = Vec::new();
+    let mut skipping = false;
+
+    for (x, line) in source.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if line.starts_with('#') {
+            skipping = true;
+            continue;
+        }
+
+        // the closing brace of a select expression may be unindented
+        if line.starts_with(char::is_whitespace) || line.starts_with('}') {
+            if line.trim_start().starts_with('.') {
+                skipping = true;
+            }
+
+            match entries.last_mut() {
+                Some((_, _, value)) if !skipping => {
+                    // a value may start on the line after its ID
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+                    value.push_str(line.trim());
+                }
+                Some(_) => {}
+                None => return Err(anyhow!("line {}: expected a message", x + 1)),
+            }
+
+            continue;
+        }
+
+        let (id, value) = line
+            .split_once('=')
+            .ok_or_else(|| anyhow!("line {}: expected '='", x + 1))?;
+        let id = id.trim();
+        let name = id.strip_prefix('-').unwrap_or(id);
+        if !name.starts_with(|ch: char| ch.is_ascii_alphabetic())
+            || !name
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+        {
+            return Err(anyhow!("line {}: invalid identifier '{}'", x + 1, id));
+        }
+
+        skipping = false;
+        entries.push((x + 1, id.to_string(), value.trim().to_string()));
+    }
+
+    Ok(entries)
+}
+
+/// Parse the value of an entry, inlining the terms already parsed.
+fn parse_message(source: &str, terms: &BTreeMap<String, String>) -> Result<FluentMessage> {
+    let mut parser = Parser {
+        chars: source.chars().collect(),
+        pos: 0,
+        terms,
+    };
+
+    let mut prefix = String::new();
+    let mut select = None;
+    let mut suffix = String::new();
+
+    while parser.pos < parser.chars.len() {
+        let text = match select {
+            None => &mut prefix,
+            Some(_) => &mut suffix,
+        };
+
+        match parser.placeable(text, true)? {
+            Some(variants) if select.is_none() => select = Some(variants),
+            Some(_) => return Err(anyhow!("only one select expression is supported")),
+            None => {}
+        }
+    }
+
+    let Some((variants, default)) = select else {
+        return Ok(FluentMessage {
+            value: prefix,
+            variants: Vec::new(),
+        });
+    };
+
+    let variants: Vec<(String, String)> = variants
+        .into_iter()
+        .map(|(key, value)| (key, format!("{}{}{}", prefix, value, suffix)))
+        .collect();
+
+    Ok(FluentMessage {
+        value: variants[default].1.clone(),
+        variants,
+    })
+}
+
+type Variants = (Vec<(String, String)>, usize);
+
+struct Parser<'t> {
+    chars: Vec<char>,
+    pos: usize,
+    terms: &'t BTreeMap<String, String>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Result<()> {
+        self.skip_whitespace();
+        if self.peek() != Some(ch) {
+            return Err(anyhow!("expected '{}' at column {}", ch, self.pos + 1));
+        }
+
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+        {
+            self.pos += 1;
+        }
+
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Write the next character of text, or the placeable starting at it, into `text`. A select
+    /// expression is returned instead, if `allow_select`.
+    fn placeable(&mut self, text: &mut String, allow_select: bool) -> Result<Option<Variants>> {
+        match self.peek() {
+            Some('{') => self.pos += 1,
+            Some('}') => return Err(anyhow!("unbalanced '}}' at column {}", self.pos + 1)),
+            Some(ch) => {
+                text.push(ch);
+                self.pos += 1;
+                return Ok(None);
+            }
+            None => return Ok(None),
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some('"') => {
+                self.pos += 1;
+                loop {
+                    match self.peek() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            self.pos += 1;
+                            text.extend(self.peek());
+                        }
+                        Some(ch) => text.push(ch),
+                        None => return Err(anyhow!("unclosed string literal")),
+                    }
+                    self.pos += 1;
+                }
+                self.pos += 1;
+            }
+            Some('$') => {
+                self.pos += 1;
+                let variable = self.identifier();
+                self.skip_whitespace();
+                if self.chars[self.pos..].starts_with(&['-', '>']) {
+                    if !allow_select {
+                        return Err(anyhow!("nested select expressions are not supported"));
+                    }
+
+                    self.pos += 2;
+                    return self.variants().map(Some);
+                }
+
+                text.push_str(&format!("%{{{}}}", variable));
+            }
+            Some('-') => {
+                self.pos += 1;
+                let term = self.identifier();
+                self.unsupported_call()?;
+                let value = self
+                    .terms
+                    .get(&term)
+                    .ok_or_else(|| anyhow!("unknown term '-{}'", term))?;
+                text.push_str(value);
+            }
+            Some(ch) if ch.is_ascii_alphabetic() => {
+                let id = self.identifier();
+                self.unsupported_call()?;
+                text.push_str(&format!("%{{{}}}", id.replace('-', ".")));
+            }
+            Some(ch) if ch.is_ascii_digit() => text.push_str(&self.identifier()),
+            _ => return Err(anyhow!("unsupported placeable at column {}", self.pos + 1)),
+        }
+
+        self.expect('}')?;
+        Ok(None)
+    }
+
+    /// Fail on what may follow a term or message reference in Fluent, but not in this subset.
+    fn unsupported_call(&mut self) -> Result<()> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('(') => Err(anyhow!("functions and term arguments are not supported")),
+            Some('.') => Err(anyhow!("attribute references are not supported")),
+            Some('-') if self.chars.get(self.pos + 1) == Some(&'>') => {
+                Err(anyhow!("only variables can be selected on"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse the variants of a select expression up to its closing brace.
+    fn variants(&mut self) -> Result<Variants> {
+        let mut variants = Vec::new();
+        let mut default = None;
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => break,
+                Some('*') => {
+                    if default.is_some() {
+                        return Err(anyhow!("more than one default variant"));
+                    }
+
+                    default = Some(variants.len());
+                    self.pos += 1;
+                }
+                None => return Err(anyhow!("unclosed select expression")),
+                _ => {}
+            }
+
+            self.expect('[')?;
+            let start = self.pos;
+            while self.peek().is_some_and(|ch| ch != ']') {
+                self.pos += 1;
+            }
+            let key: String = self.chars[start..self.pos].iter().collect();
+            self.expect(']')?;
+
+            let mut value = String::new();
+            loop {
+                match self.peek() {
+                    Some('}') | None => break,
+                    Some('\n') => {
+                        let rest = self.chars[self.pos..].iter().find(|ch| !ch.is_whitespace());
+                        if matches!(rest, Some('[' | '*' | '}')) {
+                            break;
+                        }
+
+                        value.push('\n');
+                        self.pos += 1;
+                    }
+                    _ => {
+                        self.placeable(&mut value, false)?;
+                    }
+                }
+            }
+
+            variants.push((key.trim().to_string(), value.trim().to_string()));
+        }
+
+        self.pos += 1;
+        let default = default.ok_or_else(|| anyhow!("missing a default variant"))?;
+        Ok((variants, default))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_fluent() {
+        use super::*;
+        use crate::enums::{PluralCategory, Usage};
+        use crate::time_boundary::TimeBoundary;
+        use crate::translator::{Template, Translator};
+
+        let source = r#"
+# Russian units, as delivered
+-ago = назад
+language = ru
+days = { $count ->
+    [one] день
+    [few] дня
+   *[many] дней
+}
+past-days = { $count ->
+    [0] дней
+    [one] день
+   *[other] дня
+}
+hour_short = ч
+quantity = { $count } { $unit }
+past = { $duration } { -ago }
+yesterday = вчера
+    .title = Вчера
+today =
+    сегодня
+in = через { "{" }
+"#;
+
+        let catalog: FluentCatalog = source.parse().unwrap();
+        assert_eq!(None, catalog.language());
+        assert_eq!(Some("дней"), catalog.message("days"));
+        assert_eq!(Some("день"), catalog.plural("days", 21));
+        assert_eq!(Some("дня"), catalog.plural("days", 3));
+        assert_eq!(Some("дней"), catalog.plural("days", 11));
+        assert_eq!(Some("дней"), catalog.plural("past-days", 0));
+        assert_eq!(None, catalog.plural("hour_short", 3));
+        assert_eq!(Some("%{duration} назад"), catalog.message("past"));
+        assert_eq!(Some("вчера"), catalog.message("yesterday"));
+        assert_eq!(Some("сегодня"), catalog.message("today"));
+        assert_eq!(Some("через {"), catalog.message("in"));
+
+        let map = catalog.translation_map();
+        assert_eq!(
+            Some(&"дня"),
+            map.get(&Words::PluralForm(TimeBoundary::Day, PluralCategory::Few))
+        );
+        assert_eq!(
+            Some(&"дня"),
+            map.get(&Words::Variant(
+                Usage::Past,
+                Box::new(Words::PluralForm(TimeBoundary::Day, PluralCategory::Few))
+            ))
+        );
+
+        assert_eq!(
+            Some(Cow::from("дня")),
+            Translate::translate(
+                &catalog,
+                &Words::PluralForm(TimeBoundary::Day, PluralCategory::Few)
+            )
+        );
+
+        let translator = Translator::from_catalog(&catalog);
+        let mut template = Template::default();
+        for count in [1, 2, 5] {
+            let mut inner = Template::default();
+            inner.quantity(count, TimeBoundary::Day);
+            template.phrase(crate::enums::Pattern::Past, inner);
+            template.literal(", ");
+        }
+        assert_eq!(
+            "1 день назад, 2 дня назад, 5 дня назад, ",
+            translator.render(&template)
+        );
+        assert_eq!(translator.render(&template), catalog.render(&template));
+
+        for (bad, error) in [
+            ("days = { $count ->\n [one] a\n [other] b\n}", "default"),
+            ("days = { $n ->\n *[one] { $m ->\n *[x] y\n }\n}", "nested"),
+            ("days = { -missing }", "unknown term"),
+            (
+                "days = { $a ->\n *[x] y\n} { $b ->\n *[x] z\n}",
+                "only one select expression",
+            ),
+            ("days = { NUMBER($n) }", "functions"),
+            ("-t = x\ndays = { -t(case: \"x\") }", "term arguments"),
+            ("days = { yesterday.title }", "attribute references"),
+            ("-t = x\ndays = { -t ->\n *[x] y\n}", "only variables"),
+            ("days = { $n", "expected '}'"),
+            ("days", "expected '='"),
+            ("9days = x", "invalid identifier"),
+            ("  = x", "expected a message"),
+        ] {
+            let e = bad.parse::<FluentCatalog>().unwrap_err().to_string();
+            assert!(e.contains(error), "{}: {}", bad, e);
+        }
+
+        let path = std::env::temp_dir().join(format!("speakable-time-{}-ftl", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        let path = path.join("ru.ftl");
+        std::fs::write(&path, "days = { $count ->\n [one] день\n *[many] дней\n}\n").unwrap();
+        let catalog = FluentCatalog::open(&path).unwrap();
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(Some("ru"), catalog.language());
+        assert_eq!(Some("день"), catalog.plural("days", 21));
+
+        // the translator is kept between calls, but not across a change of language
+        let language = |catalog: &FluentCatalog| {
+            Translate::translate(catalog, &Words::Language).map(|x| x.into_owned())
+        };
+        assert_eq!(Some("ru".to_string()), language(&catalog));
+        assert_eq!(catalog, catalog.clone());
+        assert_eq!(
+            Some("en".to_string()),
+            language(&catalog.clone().with_language("en"))
+        );
+        assert_eq!(Some("дней"), catalog.with_language("en").plural("days", 21));
+        assert!(FluentCatalog::open(&path).is_err());
+    }
+}
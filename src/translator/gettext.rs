@@ -0,0 +1,748 @@
+use super::catalog::CatalogTranslator;
+use super::{Catalog, Template, Translate};
+use crate::enums::Words;
+use anyhow::{anyhow, Result};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// A gettext catalog, read from a `.po` or compiled `.mo` file, for building a
+/// [Translator](super::Translator) with
+/// [Translator::from_catalog](super::Translator::from_catalog).
+///
+/// Message IDs are locale keys: the `msgctxt` of a message if it has one, so that the `msgid` can
+/// hold the English source text, else its `msgid`. A plural message is found under both its
+/// `msgid` and `msgid_plural`, and its `msgstr[N]` forms are chosen with the `Plural-Forms`
+/// formula of the header:
+///
+/// ```po
+/// "Language: ru\n"
+/// "Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+///
+/// msgid "day"
+/// msgid_plural "days"
+/// msgstr[0] "день"
+/// msgstr[1] "дня"
+/// msgstr[2] "дней"
+/// ```
+///
+/// Untranslated messages, with an empty `msgstr`, and fuzzy ones are left out.
+///
+/// Catalogs are read by this crate rather than by a gettext library. A `.po` file may use the
+/// keywords above, strings continued over several lines and comments, including obsolete `#~`
+/// entries, which are skipped. Strings may only use the escapes `\n`, `\t`, `\r`, `\"` and `\\`.
+/// The `plural=` formula may use `n`, numbers, parentheses, `?:` and the C operators `!`, `||`,
+/// `&&`, comparisons and arithmetic, up to 256 tokens. Anything else is an error.
+///
+/// The catalog implements [Translate] with a [Translator](super::Translator) built from it the
+/// first time a word is translated or a template rendered, and kept until its language is changed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GettextCatalog {
+    language: Option<String>,
+    plural_forms: Option<Expression>,
+    messages: BTreeMap<String, GettextMessage>,
+    translator: CatalogTranslator,
+}
+
+/// The forms of a message, and whether it was found under a `msgid_plural`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct GettextMessage {
+    forms: Vec<String>,
+    plural: Option<bool>,
+}
+
+/// A message as written in a catalog, before it is keyed.
+#[derive(Clone, Debug, Default)]
+struct Entry {
+    context: Option<String>,
+    id: String,
+    id_plural: Option<String>,
+    forms: Vec<String>,
+    fuzzy: bool,
+}
+
+/// The field of an [Entry] a `.po` line sets.
+#[derive(Clone, Copy, Debug)]
+enum Field {
+    Context,
+    Id,
+    IdPlural,
+    Form,
+}
+
+impl Entry {
+    fn field(&mut self, field: Option<Field>) -> Option<&mut String> {
+        match field? {
+            Field::Context => self.context.as_mut(),
+            Field::Id => Some(&mut self.id),
+            Field::IdPlural => self.id_plural.as_mut(),
+            Field::Form => self.forms.last_mut(),
+        }
+    }
+}
+
+impl GettextCatalog {
+    /// Read a catalog from a `.po` or `.mo` file. The language is taken from the `Language` header,
+    /// or else from the name of the file, such as `ru.po`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let name = path.to_string_lossy();
+        let contents = std::fs::read(path).map_err(|e| anyhow!("{}: {}", name, e))?;
+
+        let catalog = match path.extension().and_then(|x| x.to_str()) {
+            Some("po") => Self::from_po(
+                std::str::from_utf8(&contents).map_err(|e| anyhow!("{}: {}", name, e))?,
+            ),
+            Some("mo") => Self::from_mo(&contents),
+            _ => Err(anyhow!("not a .po or .mo file")),
+        };
+
+        let mut catalog = catalog.map_err(|e| anyhow!("{}: {}", name, e))?;
+        if catalog.language.is_none() {
+            catalog.language = path
+                .file_stem()
+                .and_then(|x| x.to_str())
+                .map(|x| x.to_string());
+        }
+
+        Ok(catalog)
+    }
+
+    /// Parse the contents of a `.po` file. Errors give the offending line.
+    pub fn from_po(source: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        let mut entry = Entry::default();
+        let mut fuzzy = false;
+        let mut has_forms = false;
+        // the field continuation lines are appended to
+        let mut field = None;
+
+        for (x, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some(flags) = comment.strip_prefix(',') {
+                    fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+                }
+                field = None;
+                continue;
+            }
+
+            let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let text = || unquote(rest.trim()).map_err(|e| anyhow!("line {}: {}", x + 1, e));
+
+            if keyword.starts_with('"') {
+                let text = unquote(line).map_err(|e| anyhow!("line {}: {}", x + 1, e))?;
+                match entry.field(field) {
+                    Some(field) => field.push_str(&text),
+                    None => return Err(anyhow!("line {}: unexpected string", x + 1)),
+                }
+                continue;
+            }
+
+            if matches!(keyword, "msgctxt" | "msgid") && has_forms {
+                entries.push(std::mem::take(&mut entry));
+                has_forms = false;
+            }
+
+            match keyword {
+                "msgctxt" => {
+                    entry.fuzzy = std::mem::take(&mut fuzzy);
+                    entry.context = Some(text()?);
+                    field = Some(Field::Context);
+                }
+                "msgid" => {
+                    if entry.context.is_none() {
+                        entry.fuzzy = std::mem::take(&mut fuzzy);
+                    }
+                    entry.id = text()?;
+                    field = Some(Field::Id);
+                }
+                "msgid_plural" => {
+                    entry.id_plural = Some(text()?);
+                    field = Some(Field::IdPlural);
+                }
+                "msgstr" => {
+                    has_forms = true;
+                    entry.forms.push(text()?);
+                    field = Some(Field::Form);
+                }
+                _ => {
+                    let index = keyword
+                        .strip_prefix("msgstr[")
+                        .and_then(|x| x.strip_suffix(']'))
+                        .and_then(|x| x.parse::<usize>().ok())
+                        .ok_or_else(|| anyhow!("line {}: unknown keyword '{}'", x + 1, keyword))?;
+
+                    if index != entry.forms.len() {
+                        return Err(anyhow!(
+                            "line {}: expected msgstr[{}]",
+                            x + 1,
+                            entry.forms.len()
+                        ));
+                    }
+
+                    has_forms = true;
+                    entry.forms.push(text()?);
+                    field = Some(Field::Form);
+                }
+            }
+        }
+
+        if has_forms {
+            entries.push(entry);
+        }
+
+        Self::from_entries(entries)
+    }
+
+    /// Parse the contents of a compiled `.mo` file, in either byte order.
+    pub fn from_mo(bytes: &[u8]) -> Result<Self> {
+        let word = |at: usize, big: bool| -> Result<usize> {
+            let word: [u8; 4] = bytes
+                .get(at..at + 4)
+                .and_then(|x| x.try_into().ok())
+                .ok_or_else(|| anyhow!("truncated file"))?;
+            Ok(match big {
+                true => u32::from_be_bytes(word),
+                false => u32::from_le_bytes(word),
+            } as usize)
+        };
+
+        let big = match word(0, false)? {
+            0x950412de => false,
+            0xde120495 => true,
+            _ => return Err(anyhow!("not a .mo file")),
+        };
+
+        let (count, ids, forms) = (word(8, big)?, word(12, big)?, word(16, big)?);
+        let string = |table: usize, n: usize| -> Result<&str> {
+            let length = word(table + n * 8, big)?;
+            let offset = word(table + n * 8 + 4, big)?;
+            let bytes = bytes
+                .get(offset..offset + length)
+                .ok_or_else(|| anyhow!("truncated file"))?;
+            std::str::from_utf8(bytes).map_err(|e| anyhow!("{}", e))
+        };
+
+        let mut entries = Vec::new();
+        for n in 0..count {
+            let (context, id) = match string(ids, n)?.split_once('\u{4}') {
+                Some((context, id)) => (Some(context.to_string()), id),
+                None => (None, string(ids, n)?),
+            };
+            let (id, id_plural) = match id.split_once('\0') {
+                Some((id, plural)) => (id, Some(plural.to_string())),
+                None => (id, None),
+            };
+
+            entries.push(Entry {
+                context,
+                id: id.to_string(),
+                id_plural,
+                forms: string(forms, n)?
+                    .split('\0')
+                    .map(|x| x.to_string())
+                    .collect(),
+                fuzzy: false,
+            });
+        }
+
+        Self::from_entries(entries)
+    }
+
+    /// Set the language of the catalog, in place of its `Language` header.
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self.translator = CatalogTranslator::default();
+        self
+    }
+
+    fn from_entries(entries: Vec<Entry>) -> Result<Self> {
+        let mut catalog = Self::default();
+
+        for entry in entries {
+            if entry.id.is_empty() && entry.context.is_none() {
+                for line in entry.forms.first().map(|x| x.lines()).into_iter().flatten() {
+                    match line.split_once(':') {
+                        Some(("Language", language)) if !language.trim().is_empty() => {
+                            catalog.language = Some(language.trim().to_string())
+                        }
+                        Some(("Plural-Forms", forms)) => {
+                            catalog.plural_forms = Some(Expression::from_header(forms)?)
+                        }
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+
+            if entry.fuzzy || entry.forms.iter().all(|x| x.is_empty()) {
+                continue;
+            }
+
+            let plural = entry.id_plural.is_some().then_some(false);
+            if let (Some(id_plural), None) = (&entry.id_plural, &entry.context) {
+                catalog.messages.insert(
+                    id_plural.clone(),
+                    GettextMessage {
+                        forms: entry.forms.clone(),
+                        plural: Some(true),
+                    },
+                );
+            }
+
+            catalog.messages.insert(
+                entry.context.unwrap_or(entry.id),
+                GettextMessage {
+                    forms: entry.forms,
+                    plural,
+                },
+            );
+        }
+
+        Ok(catalog)
+    }
+
+    /// The index of the `msgstr[N]` form for a number.
+    fn form(&self, count: i64) -> usize {
+        match &self.plural_forms {
+            Some(expression) => expression.evaluate(count.unsigned_abs()) as usize,
+            None => usize::from(count != 1),
+        }
+    }
+}
+
+impl Catalog for GettextCatalog {
+    fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.messages.keys().map(|x| x.as_str()))
+    }
+
+    /// The translation of a message. A plural message gives its form for one under its `msgid`,
+    /// and its last form under its `msgid_plural`.
+    fn message(&self, id: &str) -> Option<&str> {
+        let message = self.messages.get(id)?;
+        let form = match message.plural {
+            Some(true) => message.forms.last(),
+            Some(false) => message.forms.get(self.form(1)),
+            None => message.forms.first(),
+        };
+
+        form.map(|x| x.as_str()).filter(|x| !x.is_empty())
+    }
+
+    fn plural(&self, id: &str, count: i64) -> Option<&str> {
+        let message = self.messages.get(id)?;
+        message.plural?;
+        message
+            .forms
+            .get(self.form(count))
+            .map(|x| x.as_str())
+            .filter(|x| !x.is_empty())
+    }
+}
+
+impl Translate for GettextCatalog {
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+        Translate::translate(self.translator.get(self), word)
+    }
+
+    fn render_into(&self, template: &Template, s: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        Translate::render_into(self.translator.get(self), template, s)
+    }
+}
+
+/// Remove the quotes around a C string and resolve its escapes.
+fn unquote(s: &str) -> Result<String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|x| x.strip_suffix('"'))
+        .filter(|_| s.len() >= 2)
+        .ok_or_else(|| anyhow!("expected a quoted string"))?;
+
+    let mut text = String::new();
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            text.push(ch);
+            continue;
+        }
+
+        text.push(match chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some(ch @ ('"' | '\\')) => ch,
+            Some(ch) => return Err(anyhow!("unknown escape '\\{}'", ch)),
+            None => return Err(anyhow!("unterminated escape")),
+        });
+    }
+
+    Ok(text)
+}
+
+/// A `plural=` formula of a `Plural-Forms` header, in the subset of C it is written in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expression {
+    N,
+    Number(u64),
+    Not(Box<Expression>),
+    Binary(&'static str, Box<Expression>, Box<Expression>),
+    Conditional(Box<Expression>, Box<Expression>, Box<Expression>),
+}
+
+/// The most tokens a formula may have, which bounds how deeply it nests. Real formulas have a few
+/// dozen.
+const MAX_TOKENS: usize = 256;
+
+/// Binary operators, from the loosest binding to the tightest.
+const OPERATORS: &[&[&str]] = &[
+    &["||"],
+    &["&&"],
+    &["==", "!="],
+    &["<=", ">=", "<", ">"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+impl Expression {
+    /// Parse the formula from the value of a `Plural-Forms` header, such as
+    /// `nplurals=2; plural=(n != 1);`.
+    fn from_header(header: &str) -> Result<Self> {
+        let formula = header
+            .split(';')
+            .find_map(|x| x.trim().strip_prefix("plural="))
+            .ok_or_else(|| anyhow!("Plural-Forms has no plural formula"))?;
+
+        let tokens = tokenize(formula)?;
+        if tokens.len() > MAX_TOKENS {
+            return Err(anyhow!("Plural-Forms: formula is too long"));
+        }
+
+        let mut pos = 0;
+        let expression = Self::conditional(&tokens, &mut pos)?;
+        if pos != tokens.len() {
+            return Err(anyhow!("Plural-Forms: unexpected '{}'", tokens[pos]));
+        }
+
+        Ok(expression)
+    }
+
+    fn conditional(tokens: &[String], pos: &mut usize) -> Result<Self> {
+        let condition = Self::binary(tokens, pos, 0)?;
+        if tokens.get(*pos).map(|x| x.as_str()) != Some("?") {
+            return Ok(condition);
+        }
+
+        *pos += 1;
+        let then = Self::conditional(tokens, pos)?;
+        if tokens.get(*pos).map(|x| x.as_str()) != Some(":") {
+            return Err(anyhow!("Plural-Forms: expected ':'"));
+        }
+
+        *pos += 1;
+        let otherwise = Self::conditional(tokens, pos)?;
+        Ok(Self::Conditional(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    fn binary(tokens: &[String], pos: &mut usize, level: usize) -> Result<Self> {
+        let Some(operators) = OPERATORS.get(level) else {
+            return Self::unary(tokens, pos);
+        };
+
+        let mut left = Self::binary(tokens, pos, level + 1)?;
+        while let Some(operator) = tokens
+            .get(*pos)
+            .and_then(|token| operators.iter().find(|x| **x == token))
+        {
+            *pos += 1;
+            let right = Self::binary(tokens, pos, level + 1)?;
+            left = Self::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    fn unary(tokens: &[String], pos: &mut usize) -> Result<Self> {
+        let token = tokens
+            .get(*pos)
+            .ok_or_else(|| anyhow!("Plural-Forms: unexpected end of formula"))?;
+        *pos += 1;
+
+        match token.as_str() {
+            "n" => Ok(Self::N),
+            "!" => Ok(Self::Not(Box::new(Self::unary(tokens, pos)?))),
+            "(" => {
+                let inner = Self::conditional(tokens, pos)?;
+                if tokens.get(*pos).map(|x| x.as_str()) != Some(")") {
+                    return Err(anyhow!("Plural-Forms: expected ')'"));
+                }
+
+                *pos += 1;
+                Ok(inner)
+            }
+            number => number
+                .parse()
+                .map(Self::Number)
+                .map_err(|_| anyhow!("Plural-Forms: unexpected '{}'", number)),
+        }
+    }
+
+    fn evaluate(&self, n: u64) -> u64 {
+        match self {
+            Self::N => n,
+            Self::Number(x) => *x,
+            Self::Not(x) => u64::from(x.evaluate(n) == 0),
+            Self::Conditional(condition, then, otherwise) => match condition.evaluate(n) {
+                0 => otherwise.evaluate(n),
+                _ => then.evaluate(n),
+            },
+            Self::Binary(operator, left, right) => {
+                let (a, b) = (left.evaluate(n), right.evaluate(n));
+                match *operator {
+                    "||" => u64::from(a != 0 || b != 0),
+                    "&&" => u64::from(a != 0 && b != 0),
+                    "==" => u64::from(a == b),
+                    "!=" => u64::from(a != b),
+                    "<=" => u64::from(a <= b),
+                    ">=" => u64::from(a >= b),
+                    "<" => u64::from(a < b),
+                    ">" => u64::from(a > b),
+                    "+" => a.wrapping_add(b),
+                    "-" => a.wrapping_sub(b),
+                    "*" => a.wrapping_mul(b),
+                    "/" => a.checked_div(b).unwrap_or_default(),
+                    _ => a.checked_rem(b).unwrap_or_default(),
+                }
+            }
+        }
+    }
+}
+
+/// Split a formula into numbers, `n`, parentheses and operators.
+fn tokenize(formula: &str) -> Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut rest = formula.trim();
+
+    while !rest.is_empty() {
+        let length = if rest.starts_with(|ch: char| ch.is_ascii_digit()) {
+            rest.find(|ch: char| !ch.is_ascii_digit())
+                .unwrap_or(rest.len())
+        } else if ["||", "&&", "==", "!=", "<=", ">="]
+            .iter()
+            .any(|x| rest.starts_with(x))
+        {
+            2
+        } else if rest.starts_with(|ch: char| "n!()?:<>+-*/%".contains(ch)) {
+            1
+        } else {
+            return Err(anyhow!("Plural-Forms: unexpected '{}'", rest));
+        };
+
+        tokens.push(rest[..length].to_string());
+        rest = rest[length..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_gettext() {
+        use super::*;
+        use crate::enums::{PluralCategory, Usage, Words};
+        use crate::time_boundary::TimeBoundary;
+        use crate::translator::{Template, Translator};
+
+        let source = r#"
+# Russian units, as delivered
+msgid ""
+msgstr ""
+"Language: ru\n"
+"Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && "
+"n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+msgid "day"
+msgid_plural "days"
+msgstr[0] "день"
+msgstr[1] "дня"
+msgstr[2] "дней"
+
+msgctxt "past.days"
+msgid "day"
+msgid_plural "days"
+msgstr[0] "день"
+msgstr[1] "дня"
+msgstr[2] "дней"
+
+msgctxt "past"
+msgid "%{duration} ago"
+msgstr "%{duration} назад"
+
+#, fuzzy
+msgid "yesterday"
+msgstr "завтра"
+
+msgid "today"
+msgstr ""
+
+msgid "quantity"
+msgstr "%{count} "
+"%{unit}"
+"#;
+
+        let catalog = GettextCatalog::from_po(source).unwrap();
+        assert_eq!(Some("ru"), catalog.language());
+        assert_eq!(Some("день"), catalog.message("day"));
+        assert_eq!(Some("дней"), catalog.message("days"));
+        assert_eq!(Some("день"), catalog.plural("days", 21));
+        assert_eq!(Some("дня"), catalog.plural("day", 3));
+        assert_eq!(Some("дней"), catalog.plural("past.days", 11));
+        assert_eq!(None, catalog.plural("past", 3));
+        assert_eq!(None, catalog.message("yesterday"));
+        assert_eq!(None, catalog.message("today"));
+        assert_eq!(Some("%{count} %{unit}"), catalog.message("quantity"));
+
+        let map = catalog.translation_map();
+        assert_eq!(
+            Some(&"дня"),
+            map.get(&Words::Variant(
+                Usage::Past,
+                Box::new(Words::PluralForm(TimeBoundary::Day, PluralCategory::Few))
+            ))
+        );
+
+        assert_eq!(
+            Some(Cow::from("дней")),
+            Translate::translate(
+                &catalog,
+                &Words::PluralForm(TimeBoundary::Day, PluralCategory::Many)
+            )
+        );
+
+        let translator = Translator::from_catalog(&catalog);
+        let mut template = Template::default();
+        for count in [1, 2, 5] {
+            let mut inner = Template::default();
+            inner.quantity(count, TimeBoundary::Day);
+            template.phrase(crate::enums::Pattern::Past, inner);
+            template.literal(", ");
+        }
+        assert_eq!(
+            "1 день назад, 2 дня назад, 5 дней назад, ",
+            translator.render(&template)
+        );
+        assert_eq!(translator.render(&template), catalog.render(&template));
+
+        // deep formulas are refused before they are parsed or evaluated
+        for formula in [
+            format!("{}n{}", "(".repeat(100_000), ")".repeat(100_000)),
+            format!("n{}", "+n".repeat(100_000)),
+        ] {
+            let header = format!("Plural-Forms: nplurals=2; plural={};", formula);
+            let e = Expression::from_header(&header).unwrap_err().to_string();
+            assert!(e.contains("too long"), "{}", e);
+        }
+
+        let english = GettextCatalog::from_po(
+            "msgid \"day\"\nmsgid_plural \"days\"\nmsgstr[0] \"jour\"\nmsgstr[1] \"jours\"\n",
+        )
+        .unwrap();
+        assert_eq!(Some("jours"), english.plural("day", 0));
+        assert_eq!(Some("jour"), english.with_language("fr").plural("day", 1));
+
+        for (bad, error) in [
+            (
+                "msgid \"day\nmsgstr \"\"",
+                "line 1: expected a quoted string",
+            ),
+            (
+                "msgid \"day\"\nmsgstr[1] \"x\"",
+                "line 2: expected msgstr[0]",
+            ),
+            ("msgid \"a\\q\"", "unknown escape"),
+            ("\"day\"", "line 1: unexpected string"),
+            ("msgstring \"x\"", "unknown keyword"),
+            (
+                "msgid \"\"\nmsgstr \"Plural-Forms: nplurals=2; plural=(n != 1;\\n\"",
+                "expected ')'",
+            ),
+            (
+                "msgid \"\"\nmsgstr \"Plural-Forms: nplurals=2; plural=n & 1;\\n\"",
+                "unexpected '&",
+            ),
+        ] {
+            let e = GettextCatalog::from_po(bad).unwrap_err().to_string();
+            assert!(e.contains(error), "{}: {}", bad, e);
+        }
+
+        // a .mo file of the header, "day"/"days" and "past.days" with a context
+        let strings: [(&str, &str); 3] = [
+            ("", "Language: ru\nPlural-Forms: nplurals=3; plural=n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2;\n"),
+            ("day\0days", "день\0дня\0дней"),
+            ("past.days\u{4}day\0days", "день\0дня\0дней"),
+        ];
+        let mut mo = Vec::new();
+        let header = 28;
+        let mut data = Vec::new();
+        let mut tables = Vec::new();
+        for side in [0, 1] {
+            for (id, form) in strings {
+                let s = if side == 0 { id } else { form };
+                tables.push((
+                    s.len() as u32,
+                    (header + 16 * strings.len() + data.len()) as u32,
+                ));
+                data.extend(s.as_bytes());
+                data.push(0);
+            }
+        }
+        for word in [
+            0x950412de,
+            0,
+            strings.len() as u32,
+            header as u32,
+            (header + 8 * strings.len()) as u32,
+            0,
+            0,
+        ] {
+            mo.extend(u32::to_le_bytes(word));
+        }
+        for (length, offset) in tables {
+            mo.extend(length.to_le_bytes());
+            mo.extend(offset.to_le_bytes());
+        }
+        mo.extend(data);
+
+        let catalog = GettextCatalog::from_mo(&mo).unwrap();
+        assert_eq!(Some("ru"), catalog.language());
+        assert_eq!(Some("дня"), catalog.plural("days", 22));
+        assert_eq!(Some("дней"), catalog.plural("past.days", 12));
+        assert!(GettextCatalog::from_mo(&mo[..30]).is_err());
+        assert!(GettextCatalog::from_mo(b"not a catalog").is_err());
+
+        let path = std::env::temp_dir().join(format!("speakable-time-{}-mo", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(path.join("ru.mo"), &mo).unwrap();
+        std::fs::write(path.join("de.po"), "msgid \"days\"\nmsgstr \"Tage\"\n").unwrap();
+        let russian = GettextCatalog::open(path.join("ru.mo")).unwrap();
+        let german = GettextCatalog::open(path.join("de.po")).unwrap();
+        assert!(GettextCatalog::open(path.join("fr.po")).is_err());
+        std::fs::remove_dir_all(&path).unwrap();
+        assert_eq!(catalog, russian);
+        assert_eq!(Some("de"), german.language());
+        assert_eq!(Some("Tage"), german.message("days"));
+    }
+}
@@ -1,40 +1,478 @@
 #[cfg(feature = "translation")]
 mod real {
+    use crate::translator::Translator;
     use include_dir::{include_dir, Dir};
     pub static LOCALES: Dir = include_dir!("st-locales");
 
+    /// The locales consulted for a locale, most specific first. `en-GB` yields `en-GB`, `en` and
+    /// then `C`, which is always last.
+    pub fn locale_chain(locale: &str) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut parts: Vec<&str> = locale.split('-').filter(|x| !x.is_empty()).collect();
+
+        while !parts.is_empty() {
+            chain.push(parts.join("-"));
+            parts.pop();
+        }
+
+        if !chain.iter().any(|x| x == "C") {
+            chain.push("C".to_string());
+        }
+
+        chain
+    }
+
+    /// Build a layered [Translator] from the embedded locales named by [locale_chain]. Each word is
+    /// looked up in the most specific locale which has it, so regional files only need to contain
+    /// the words that differ. See [load_locale](crate::load_locale).
+    pub fn load_embedded(locale: &str) -> Translator<'static> {
+        let maps = locale_chain(locale).into_iter().filter_map(|name| {
+            LOCALES.get_file(format!("{}.yml", name)).map(|file| {
+                serde_yaml::from_str(file.contents_utf8().unwrap())
+                    .unwrap_or_else(|e| panic!("Could not parse {}.yml: {}", name, e))
+            })
+        });
+
+        Translator::layered(maps).expect("Could not find any translations")
+    }
+
     /// All locales in `st-locales` are loaded, with the default being `C.yml`, and then using
     /// standard locale terms: `en.yml` would work, or `en-US.yml`, or `zh-CN.yml`, etc. Each one
     /// of these contains a mapping of the [Words](crate::enums::Words) to the appropriate words
     /// that correspond in a given language. This allows dynamic translation of several
     /// grammatically-similar languages into native tongues without sacrificing structure or
     /// information.
+    ///
+    /// The files are layered: for `en-GB`, words are taken from `en-GB.yml`, then `en.yml`, then
+    /// `C.yml`, whichever has them first. See [load_embedded].
     #[macro_export]
     macro_rules! load_locale {
         ($locale:expr) => {{
-            use $crate::translator::LOCALES;
+            $crate::translator::load_embedded(&$locale)
+        }};
+    }
+}
 
-            let mut file = LOCALES.get_file(format!("{}.yml", $locale));
-            if file.is_none() {
-                file = LOCALES.get_file(format!("{}.yml", $locale.split("-").nth(0).unwrap()));
+#[cfg(feature = "translation")]
+pub use self::real::*;
+
+#[cfg(all(test, feature = "translation"))]
+mod tests {
+    #[test]
+    fn test_embedded_golden() {
+        use super::*;
+        use crate::{
+            approximate::{format_generator::FormatGenerator, ApproximateState, StateCollection},
+            enums::{Modifier, Words},
+            time_boundary::TimeBoundary::*,
+            translator::{Template, Translator},
+            CoarseRoundFormat, FancyDurationFormat,
+        };
+
+        let cases = [
+            (true, vec![(Year, 2), (Month, 5), (Day, 3)]),
+            (false, vec![(Day, 1)]),
+            (true, vec![(Week, 1), (Hour, 21)]),
+            (false, vec![(Minute, 5), (Second, 2)]),
+        ];
+
+        let render = |translator: &Translator<'_>, generator: &mut dyn FormatGenerator| {
+            cases
+                .iter()
+                .map(|(past, values)| {
+                    let mut states = StateCollection::default();
+                    states.push(ApproximateState::InPast(*past));
+                    for (boundary, value) in values {
+                        states.push(ApproximateState::Value(boundary.clone(), *value));
+                    }
+
+                    generator.clear();
+                    generator.add(states);
+                    translator.render_checked(&generator.template()).unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let golden: &[(&str, [&str; 4], [&str; 4], &str)] = &[
+            (
+                "es",
+                [
+                    "hace 2 años, 5 meses y 3 días",
+                    "dentro de 1 día",
+                    "hace 1 semana y 21 horas",
+                    "dentro de 5 minutos y 2 segundos",
+                ],
+                ["hace 2y5mo3d", "en 1d", "hace 1w21h", "en 5m2s"],
+                "la semana pasada, el próximo lunes, este mes",
+            ),
+            (
+                "fr",
+                [
+                    "il y a 2 ans, 5 mois et 3 jours",
+                    "dans 1 jour",
+                    "il y a 1 semaine et 21 heures",
+                    "dans 5 minutes et 2 secondes",
+                ],
+                ["il y a 2y5mo3d", "dans 1d", "il y a 1w21h", "dans 5m2s"],
+                "la semaine dernière, le lundi prochain, ce mois",
+            ),
+            (
+                "de",
+                [
+                    "vor 2 Jahren, 5 Monaten und 3 Tagen",
+                    "in 1 Tag",
+                    "vor 1 Woche und 21 Stunden",
+                    "in 5 Minuten und 2 Sekunden",
+                ],
+                ["vor 2y5mo3d", "in 1d", "vor 1w21h", "in 5m2s"],
+                "letzte Woche, nächsten Montag, diesen Monat",
+            ),
+            (
+                "pt",
+                [
+                    "há 2 anos, 5 meses e 3 dias",
+                    "em 1 dia",
+                    "há 1 semana e 21 horas",
+                    "em 5 minutos e 2 segundos",
+                ],
+                ["há 2y5mo3d", "em 1d", "há 1w21h", "em 5m2s"],
+                "a semana passada, a próxima segunda-feira, este mês",
+            ),
+            (
+                "ja",
+                [
+                    "2年、5か月、3日前",
+                    "1日後",
+                    "1週間、21時間前",
+                    "5分、2秒後",
+                ],
+                ["2y5mo3d前", "1d後", "1w21h前", "5m2s後"],
+                "先週、来週の月曜日、今月",
+            ),
+            (
+                "zh",
+                [
+                    "2年、5个月和3天前",
+                    "1天后",
+                    "1周和21小时前",
+                    "5分钟和2秒后",
+                ],
+                ["2y5mo3d前", "1d后", "1w21h前", "5m2s后"],
+                "上周、下星期一、本月",
+            ),
+            (
+                "ru",
+                [
+                    "2 года, 5 месяцев и 3 дня назад",
+                    "через 1 день",
+                    "1 неделю и 21 час назад",
+                    "через 5 минут и 2 секунды",
+                ],
+                ["2y5mo3d назад", "через 1d", "1w21h назад", "через 5m2s"],
+                "прошлая неделя, следующий понедельник, этот месяц",
+            ),
+            (
+                "ar",
+                [
+                    "منذ سنتين و٥ أشهر و٣ أيام",
+                    "بعد يوم",
+                    "منذ أسبوع و٢١ ساعة",
+                    "بعد ٥ دقائق وثانيتين",
+                ],
+                ["منذ ٢y٥mo٣d", "بعد ١d", "منذ ١w٢١h", "بعد ٥m٢s"],
+                "الأسبوع الماضي، الاثنين القادم، هذا الشهر",
+            ),
+        ];
+
+        for (locale, coarse, fancy, modified) in golden {
+            let translator = load_embedded(locale);
+            assert_eq!(
+                coarse.to_vec(),
+                render(&translator, &mut CoarseRoundFormat::default()),
+                "{}",
+                locale
+            );
+            assert_eq!(
+                fancy.to_vec(),
+                render(&translator, &mut FancyDurationFormat::default()),
+                "{}",
+                locale
+            );
+
+            let mut template = Template::default();
+            let nouns = [
+                (Modifier::Last, Words::Week),
+                (Modifier::Next, Words::Monday),
+                (Modifier::This, Words::Month),
+            ];
+            for (x, (modifier, noun)) in nouns.into_iter().enumerate() {
+                if x > 0 {
+                    template.literal(if *locale == "ja" || *locale == "zh" {
+                        "、"
+                    } else if *locale == "ar" {
+                        "، "
+                    } else {
+                        ", "
+                    });
+                }
+                template.modified(modifier, noun);
             }
 
-            if file.is_none() {
-                file = LOCALES.get_file("C.yml")
+            assert_eq!(
+                Ok(modified.to_string()),
+                translator.render_checked(&template),
+                "{}",
+                locale
+            );
+        }
+
+        let mut template = Template::default();
+        template.modified(Modifier::Last, Words::Year);
+        template.literal("、");
+        template.modified(Modifier::Next, Words::Year);
+        assert_eq!(
+            Ok("去年、来年".to_string()),
+            load_embedded("ja").render_checked(&template)
+        );
+    }
+
+    #[test]
+    fn test_embedded_spelled() {
+        use super::*;
+        use crate::{
+            approximate::{format_generator::FormatGenerator, ApproximateState, StateCollection},
+            time_boundary::TimeBoundary,
+            translator::Translator,
+            CoarseRoundFormat,
+        };
+
+        let golden = [
+            (
+                "C",
+                "twenty-one days and forty-five minutes ago",
+                Some("an hour from now"),
+                [
+                    "ninety-nine",
+                    "one thousand",
+                    "twenty-one thousand",
+                    "two million",
+                    "three million five hundred thousand",
+                    "one billion",
+                ],
+            ),
+            (
+                "es",
+                "hace veintiún días y cuarenta y cinco minutos",
+                Some("dentro de una hora"),
+                [
+                    "noventa y nueve",
+                    "mil",
+                    "veintiún mil",
+                    "dos millones",
+                    "tres millones quinientos mil",
+                    "mil millones",
+                ],
+            ),
+            (
+                "fr",
+                "il y a vingt et un jours et quarante-cinq minutes",
+                Some("dans une heure"),
+                [
+                    "quatre-vingt-dix-neuf",
+                    "mille",
+                    "vingt et un mille",
+                    "deux millions",
+                    "trois millions cinq cent mille",
+                    "un milliard",
+                ],
+            ),
+            (
+                "de",
+                "vor einundzwanzig Tagen und fünfundvierzig Minuten",
+                Some("in einer Stunde"),
+                [
+                    "neunundneunzig",
+                    "eintausend",
+                    "einundzwanzigtausend",
+                    "zwei Millionen",
+                    "drei Millionen fünfhunderttausend",
+                    "eine Milliarde",
+                ],
+            ),
+            (
+                "pt",
+                "há vinte e um dias e quarenta e cinco minutos",
+                Some("em uma hora"),
+                [
+                    "noventa e nove",
+                    "mil",
+                    "vinte e um mil",
+                    "dois milhões",
+                    "três milhões e quinhentos mil",
+                    "um bilhão",
+                ],
+            ),
+            (
+                "ja",
+                "二十一日、四十五分前",
+                None,
+                ["九十九", "千", "二万千", "二百万", "三百五十万", "十億"],
+            ),
+            (
+                "zh",
+                "二十一天和四十五分钟前",
+                None,
+                ["九十九", "一千", "两万一千", "两百万", "三百五十万", "十亿"],
+            ),
+            (
+                "ru",
+                "двадцать один день и сорок пять минут назад",
+                Some("через один час"),
+                [
+                    "девяносто девять",
+                    "тысяча",
+                    "двадцать одна тысяча",
+                    "два миллиона",
+                    "три миллиона пятьсот тысяч",
+                    "миллиард",
+                ],
+            ),
+            (
+                "ar",
+                "منذ واحد وعشرون يومًا وخمس وأربعون دقيقة",
+                None,
+                [
+                    "تسعة وتسعون",
+                    "ألف",
+                    "واحد وعشرون ألف",
+                    "مليونان",
+                    "ثلاثة ملايين وخمسمائة ألف",
+                    "مليار",
+                ],
+            ),
+        ];
+
+        let render = |translator: &Translator<'_>, past, values: &[(TimeBoundary, i64)]| {
+            let mut states = StateCollection::default();
+            states.push(ApproximateState::InPast(past));
+            for (boundary, value) in values {
+                states.push(ApproximateState::Value(boundary.clone(), *value));
             }
 
-            Translator::new(
-                serde_yaml::from_str(
-                    &file
-                        .expect("Could not find any translations")
-                        .contents_utf8()
-                        .unwrap(),
-                )
-                .unwrap(),
-            )
-        }};
+            let mut format = CoarseRoundFormat::default()
+                .with_spelling(100)
+                .with_articles();
+            format.add(states);
+            translator.render_checked(&format.template()).unwrap()
+        };
+
+        for (locale, spelled, article, cardinals) in golden {
+            let translator = load_embedded(locale);
+            let values = [(TimeBoundary::Day, 21), (TimeBoundary::Minute, 45)];
+            assert_eq!(spelled, render(&translator, true, &values), "{}", locale);
+
+            if let Some(article) = article {
+                let values = [(TimeBoundary::Hour, 1)];
+                assert_eq!(article, render(&translator, false, &values), "{}", locale);
+            }
+
+            let numbers = [99, 1000, 21_000, 2_000_000, 3_500_000, 1_000_000_000];
+            for (number, expected) in numbers.into_iter().zip(cardinals) {
+                assert_eq!(
+                    Some(expected.to_string()),
+                    translator.cardinal(number),
+                    "{} {}",
+                    locale,
+                    number
+                );
+            }
+        }
     }
-}
 
-#[cfg(feature = "translation")]
-pub use self::real::*;
+    #[test]
+    fn test_embedded_ordinals() {
+        use super::*;
+        use crate::{time_boundary::TimeBoundary, translator::Template};
+
+        let mut template = Template::default();
+        template.ordinal(2, TimeBoundary::Week);
+        template.literal(" / ");
+        template.ordinal(1, TimeBoundary::Day);
+        template.literal(" / ");
+        template.day_of_month(1);
+        template.literal(" / ");
+        template.day_of_month(23);
+
+        let golden = [
+            ("C", "2nd week / 1st day / 1st / 23rd"),
+            ("es", "2.ª semana / 1.º día / 1 / 23"),
+            ("fr", "2e semaine / 1er jour / 1 / 23"),
+            ("de", "2. Woche / 1. Tag / 1. / 23."),
+            ("pt", "2ª semana / 1º dia / 1 / 23"),
+            ("ja", "第2週 / 第1日 / 1日 / 23日"),
+            ("zh", "第2周 / 第1天 / 1日 / 23日"),
+            ("ru", "2-я неделя / 1-й день / 1 / 23"),
+            ("ar", "أسبوع ٢ / يوم ١ / ١ / ٢٣"),
+        ];
+
+        for (locale, expected) in golden {
+            let translator = load_embedded(locale);
+            assert_eq!(
+                Ok(expected.to_string()),
+                translator.render_checked(&template)
+            );
+            // only english spells ordinals, and no other locale borrows its rules
+            assert_eq!(
+                locale == "C",
+                translator.ordinal(23).is_some(),
+                "{}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_embedded_abbreviated() {
+        use super::*;
+        use crate::{
+            approximate::{format_generator::FormatGenerator, ApproximateState, StateCollection},
+            enums::ListWidth,
+            time_boundary::TimeBoundary,
+            FancyDurationFormat,
+        };
+
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::InPast(true));
+        states.push(ApproximateState::Value(TimeBoundary::Year, 5));
+        states.push(ApproximateState::Value(TimeBoundary::Day, 1));
+        states.push(ApproximateState::Value(TimeBoundary::Hour, 2));
+
+        let golden = [
+            ("C", ["5 yr., 1 day, 2 hr. ago", "5y 1d 2h ago"]),
+            ("es", ["hace 5 a, 1 d, 2 h", "hace 5a 1d 2h"]),
+            ("fr", ["il y a 5 ans, 1 j, 2 h", "il y a 5a 1j 2h"]),
+            ("de", ["vor 5 J., 1 Tg., 2 Std.", "vor 5 J 1 T 2 Std."]),
+            ("pt", ["há 5 anos, 1 dia, 2 h", "há 5a 1d 2h"]),
+            ("ja", ["5年1日2時間前", "5年1日2時間前"]),
+            ("zh", ["5年1天2小时前", "5年1天2小时前"]),
+            ("ru", ["5 л., 1 дн., 2 ч назад", "5 л 1 д 2 ч назад"]),
+            (
+                "ar",
+                ["منذ ٥ سنوات، يوم، ساعتين", "منذ ٥ سنوات، يوم، ساعتين"],
+            ),
+        ];
+
+        for (locale, expected) in golden {
+            let translator = load_embedded(locale);
+            for (width, expected) in [ListWidth::Short, ListWidth::Narrow]
+                .into_iter()
+                .zip(expected)
+            {
+                let mut format = FancyDurationFormat::default().with_unit_width(width);
+                format.add(states.clone());
+                let rendered = translator.render_checked(&format.template());
+                assert_eq!(Ok(expected.to_string()), rendered, "{} {:?}", locale, width);
+            }
+        }
+    }
+}
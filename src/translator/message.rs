@@ -0,0 +1,646 @@
+use super::Template;
+use crate::enums::{PluralCategory, Words};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// A typed value given to a [Message] through its [Arguments].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Argument {
+    /// A number, which can select a `plural` case and is written in the translator's
+    /// [NumberingSystem](crate::enums::NumberingSystem).
+    Number(i64),
+    /// Text which is written as-is, and can select a `select` case.
+    Text(String),
+    /// A word which is translated, and can select a `select` case by its name, such as `days`.
+    Word(Words),
+    /// A template which is rendered in place.
+    Template(Template),
+}
+
+impl From<i64> for Argument {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i32> for Argument {
+    fn from(value: i32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<&str> for Argument {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for Argument {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Words> for Argument {
+    fn from(value: Words) -> Self {
+        Self::Word(value)
+    }
+}
+
+impl From<Template> for Argument {
+    fn from(value: Template) -> Self {
+        Self::Template(value)
+    }
+}
+
+/// The named [Argument]s of a [Message].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Arguments(BTreeMap<String, Argument>);
+
+impl Arguments {
+    /// Add an argument, replacing any of the same name.
+    pub fn with(mut self, name: &str, value: impl Into<Argument>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Add an argument in place, replacing any of the same name.
+    pub fn insert(&mut self, name: &str, value: impl Into<Argument>) {
+        self.0.insert(name.to_string(), value.into());
+    }
+
+    /// The argument of a name, if given.
+    pub fn get(&self, name: &str) -> Option<&Argument> {
+        self.0.get(name)
+    }
+}
+
+/// Errors from parsing or filling a [Message]. Every error carries the position in the message
+/// where it occurred, counted in characters from zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessageError {
+    /// The message is malformed.
+    Syntax {
+        /// Where the problem was found.
+        position: usize,
+        /// What was wrong.
+        reason: &'static str,
+    },
+    /// The message uses an argument which was not given.
+    MissingArgument {
+        /// The opening brace of the argument.
+        position: usize,
+        /// The name of the argument.
+        name: String,
+    },
+    /// An argument was given a type its format cannot use, such as text for a `plural`.
+    ArgumentType {
+        /// The opening brace of the argument.
+        position: usize,
+        /// The name of the argument.
+        name: String,
+        /// The kind of [Argument] the format requires.
+        expected: &'static str,
+    },
+    /// A number is too large or too small for its `plural`'s offset to be taken from it.
+    OutOfRange {
+        /// The opening brace of the argument.
+        position: usize,
+        /// The name of the argument.
+        name: String,
+    },
+}
+
+impl MessageError {
+    /// The position in the message where the error occurred, in characters.
+    pub fn position(&self) -> usize {
+        match self {
+            Self::Syntax { position, .. }
+            | Self::MissingArgument { position, .. }
+            | Self::ArgumentType { position, .. }
+            | Self::OutOfRange { position, .. } => *position,
+        }
+    }
+}
+
+impl std::fmt::Display for MessageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax { position, reason } => {
+                write!(f, "Invalid message at position {} ({})", position, reason)
+            }
+            Self::MissingArgument { position, name } => {
+                write!(f, "Missing argument '{}' at position {}", name, position)
+            }
+            Self::ArgumentType {
+                position,
+                name,
+                expected,
+            } => write!(
+                f,
+                "Argument '{}' at position {} must be {}",
+                name, position, expected
+            ),
+            Self::OutOfRange { position, name } => write!(
+                f,
+                "Argument '{}' at position {} is out of range",
+                name, position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MessageError {}
+
+/// A compiled message in a subset of ICU MessageFormat, rendered with
+/// [Translator::render_message](crate::translator::Translator::render_message). Besides text and
+/// the `%{word}` terms of [Template]s, a message can contain arguments in braces:
+///
+/// - `{name}` writes an argument: numbers in digits, words translated and templates rendered.
+/// - `{name, number}`, `{name, spellout}` and `{name, ordinal}` write a number in digits, in
+///   words, or in digits with its ordinal suffix.
+/// - `{name, plural, one {…} other {…}}` picks a case by the
+///   [PluralCategory] of a number in the locale, after any exact `=0 {…}` cases. Within a case,
+///   `#` writes the number. An `offset:1` before the cases is taken from the number before it is
+///   classified and written. `selectordinal` picks a case by the ordinal category instead.
+/// - `{name, select, feminine {…} other {…}}` picks a case by text or by the name of a word.
+///
+/// Cases may nest, and `plural`, `selectordinal` and `select` need an `other` case. As in ICU,
+/// an apostrophe quotes any braces or `#` which follow it up to the next apostrophe, and two
+/// apostrophes write one; other apostrophes are written as-is.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Message(pub(crate) Vec<Part>);
+
+/// A piece of a [Message].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Part {
+    /// Text and `%{word}` terms.
+    Template(Template),
+    /// An argument in braces, with the position of its opening brace.
+    Placeholder(String, usize, Format),
+    /// A `#` in a plural case.
+    Pound,
+}
+
+/// How a [Part::Placeholder] writes its argument.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Format {
+    Plain,
+    Number,
+    Spellout,
+    Ordinal,
+    Plural {
+        ordinal: bool,
+        offset: i64,
+        cases: Vec<(Selector, Message)>,
+    },
+    Select(Vec<(String, Message)>),
+}
+
+/// A case of a `plural` or `selectordinal`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Selector {
+    Exact(i64),
+    Category(PluralCategory),
+}
+
+impl Message {
+    /// Check that every argument the message uses is given, with a type its format can use. Every
+    /// case is checked, not only the ones the arguments select.
+    pub fn check(&self, arguments: &Arguments) -> Result<(), MessageError> {
+        for part in &self.0 {
+            let Part::Placeholder(name, position, format) = part else {
+                continue;
+            };
+
+            let Some(argument) = arguments.get(name) else {
+                return Err(MessageError::MissingArgument {
+                    position: *position,
+                    name: name.clone(),
+                });
+            };
+
+            let expected = match (format, argument) {
+                (Format::Plain, _)
+                | (
+                    Format::Number | Format::Spellout | Format::Ordinal | Format::Plural { .. },
+                    Argument::Number(_),
+                )
+                | (Format::Select(_), Argument::Text(_) | Argument::Word(_)) => None,
+                (Format::Select(_), _) => Some("text or a word"),
+                _ => Some("a number"),
+            };
+
+            if let Some(expected) = expected {
+                return Err(MessageError::ArgumentType {
+                    position: *position,
+                    name: name.clone(),
+                    expected,
+                });
+            }
+
+            if let (Format::Plural { offset, .. }, Argument::Number(n)) = (format, argument) {
+                if n.checked_sub(*offset).is_none() {
+                    return Err(MessageError::OutOfRange {
+                        position: *position,
+                        name: name.clone(),
+                    });
+                }
+            }
+
+            match format {
+                Format::Plural { cases, .. } => {
+                    for (_, case) in cases {
+                        case.check(arguments)?
+                    }
+                }
+                Format::Select(cases) => {
+                    for (_, case) in cases {
+                        case.check(arguments)?
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Message {
+    type Err = MessageError;
+
+    /// Parse a message. See [Message] for the syntax.
+    fn from_str(message: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: message.chars().collect(),
+            pos: 0,
+        };
+
+        parser.message(false, false)
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self, position: usize, reason: &'static str) -> MessageError {
+        MessageError::Syntax { position, reason }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, ch: char, reason: &'static str) -> Result<(), MessageError> {
+        self.skip_whitespace();
+        if self.peek() != Some(ch) {
+            return Err(self.error(self.pos, reason));
+        }
+
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Read a run of characters which are not whitespace, braces or commas.
+    fn keyword(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|ch| !ch.is_whitespace() && !"{},".contains(ch))
+        {
+            self.pos += 1;
+        }
+
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn integer(&mut self) -> Result<i64, MessageError> {
+        let start = self.pos;
+        let word = self.keyword();
+        word.parse()
+            .map_err(|_| self.error(start, "expected a whole number"))
+    }
+
+    /// Parse text up to the end of the message, or up to the closing brace of a case when
+    /// `nested`. `#` is a [Part::Pound] when `in_plural`.
+    fn message(&mut self, nested: bool, in_plural: bool) -> Result<Message, MessageError> {
+        let mut parts = Vec::new();
+        let mut template = Template::default();
+        let mut text = String::new();
+
+        let flush = |parts: &mut Vec<Part>, template: &mut Template, text: &mut String| {
+            template.push_text(text);
+            text.clear();
+            if !template.is_empty() {
+                parts.push(Part::Template(std::mem::take(template)));
+            }
+        };
+
+        while let Some(ch) = self.peek() {
+            match ch {
+                '%' => match self.peek_at(1) {
+                    Some('%') => {
+                        text.push('%');
+                        self.pos += 2;
+                    }
+                    Some('{') => {
+                        let start = self.pos;
+                        self.pos += 2;
+                        let mut term = String::new();
+                        loop {
+                            match self.peek() {
+                                Some('}') => break,
+                                Some('%' | '{') => {
+                                    return Err(
+                                        self.error(self.pos, "format attempted within format")
+                                    )
+                                }
+                                Some(ch) => term.push(ch),
+                                None => return Err(self.error(start, "unclosed brace")),
+                            }
+                            self.pos += 1;
+                        }
+                        self.pos += 1;
+
+                        template.push_text(&text);
+                        text.clear();
+                        template.push(match Words::from_str(&term) {
+                            Ok(word) => super::Token::Word(word),
+                            Err(_) => super::Token::Unknown(term),
+                        });
+                    }
+                    _ => return Err(self.error(self.pos, "incomplete match")),
+                },
+                '{' => {
+                    flush(&mut parts, &mut template, &mut text);
+                    parts.push(self.placeholder(in_plural)?);
+                }
+                '}' if nested => break,
+                '}' => return Err(self.error(self.pos, "close brace outside an argument")),
+                '#' if in_plural => {
+                    flush(&mut parts, &mut template, &mut text);
+                    parts.push(Part::Pound);
+                    self.pos += 1;
+                }
+                '\'' => self.quoted(&mut text, in_plural)?,
+                ch => {
+                    text.push(ch);
+                    self.pos += 1;
+                }
+            }
+        }
+
+        flush(&mut parts, &mut template, &mut text);
+        Ok(Message(parts))
+    }
+
+    /// Parse an apostrophe, and any text it quotes.
+    fn quoted(&mut self, text: &mut String, in_plural: bool) -> Result<(), MessageError> {
+        let start = self.pos;
+        self.pos += 1;
+        match self.peek() {
+            Some('\'') => {
+                text.push('\'');
+                self.pos += 1;
+                return Ok(());
+            }
+            Some('{' | '}') => {}
+            Some('#') if in_plural => {}
+            _ => {
+                text.push('\'');
+                return Ok(());
+            }
+        }
+
+        loop {
+            match self.peek() {
+                Some('\'') if self.peek_at(1) == Some('\'') => {
+                    text.push('\'');
+                    self.pos += 2;
+                }
+                Some('\'') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Some(ch) => {
+                    text.push(ch);
+                    self.pos += 1;
+                }
+                None => return Err(self.error(start, "unclosed quote")),
+            }
+        }
+    }
+
+    /// Parse an argument in braces.
+    fn placeholder(&mut self, in_plural: bool) -> Result<Part, MessageError> {
+        let start = self.pos;
+        self.pos += 1;
+
+        let name = self.keyword();
+        if self.peek().is_none() {
+            return Err(self.error(start, "unclosed brace"));
+        }
+
+        if name.is_empty() {
+            return Err(self.error(self.pos, "expected an argument name"));
+        }
+
+        self.skip_whitespace();
+        let format = match self.peek() {
+            Some('}') => Format::Plain,
+            Some(',') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                let position = self.pos;
+                match self.keyword().as_str() {
+                    "number" => Format::Number,
+                    "spellout" => Format::Spellout,
+                    "ordinal" => Format::Ordinal,
+                    kind @ ("plural" | "selectordinal") => {
+                        self.expect(',', "expected ',' before the cases")?;
+                        self.plural(kind == "selectordinal", start)?
+                    }
+                    "select" => {
+                        self.expect(',', "expected ',' before the cases")?;
+                        Format::Select(self.cases(
+                            start,
+                            in_plural,
+                            |_, key| Ok(key),
+                            "other".to_string(),
+                        )?)
+                    }
+                    "" => return Err(self.error(position, "expected an argument type")),
+                    _ => return Err(self.error(position, "unknown argument type")),
+                }
+            }
+            None => return Err(self.error(start, "unclosed brace")),
+            _ => return Err(self.error(self.pos, "expected ',' or '}'")),
+        };
+
+        self.expect('}', "expected '}'")?;
+        Ok(Part::Placeholder(name, start, format))
+    }
+
+    /// Parse the offset and cases of a `plural` or `selectordinal`.
+    fn plural(&mut self, ordinal: bool, start: usize) -> Result<Format, MessageError> {
+        self.skip_whitespace();
+        let mut offset = 0;
+        if self.chars[self.pos..].starts_with(&['o', 'f', 'f', 's', 'e', 't', ':']) {
+            self.pos += 7;
+            self.skip_whitespace();
+            offset = self.integer()?;
+        }
+
+        let cases = self.cases(
+            start,
+            true,
+            |parser, key| {
+                let position = parser.pos - key.chars().count();
+                match key.strip_prefix('=') {
+                    Some(exact) => exact
+                        .parse()
+                        .map(Selector::Exact)
+                        .map_err(|_| parser.error(position + 1, "expected a whole number")),
+                    None => PluralCategory::from_str(&key)
+                        .map(Selector::Category)
+                        .map_err(|_| parser.error(position, "unknown plural category")),
+                }
+            },
+            Selector::Category(PluralCategory::Other),
+        )?;
+
+        Ok(Format::Plural {
+            ordinal,
+            offset,
+            cases,
+        })
+    }
+
+    /// Parse cases up to the closing brace of their argument, each a key and a message in braces.
+    /// There must be an `other` case.
+    fn cases<K: PartialEq>(
+        &mut self,
+        start: usize,
+        in_plural: bool,
+        key: impl Fn(&Self, String) -> Result<K, MessageError>,
+        other: K,
+    ) -> Result<Vec<(K, Message)>, MessageError> {
+        let mut cases = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => break,
+                None => return Err(self.error(start, "unclosed brace")),
+                _ => {}
+            }
+
+            let word = self.keyword();
+            if word.is_empty() {
+                return Err(self.error(self.pos, "expected a case"));
+            }
+
+            let selector = key(self, word)?;
+            self.expect('{', "expected '{' after the case")?;
+            let message = self.message(true, in_plural)?;
+            self.expect('}', "expected '}' after the case")?;
+            cases.push((selector, message));
+        }
+
+        if !cases.iter().any(|(selector, _)| *selector == other) {
+            return Err(self.error(start, "missing 'other' case"));
+        }
+
+        Ok(cases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_message() {
+        use super::*;
+
+        let message: Message = "%{in} {count, plural, one {# %{day}} other {# %{days}}}"
+            .parse()
+            .unwrap();
+        assert_eq!(2, message.0.len());
+        assert!(matches!(
+            &message.0[1],
+            Part::Placeholder(name, 6, Format::Plural { cases, .. })
+                if name == "count" && cases.len() == 2
+        ));
+
+        let message: Message = "it''s '{literal}' 100%% '#".parse().unwrap();
+        let mut template = Template::default();
+        template.literal("it's {literal} ");
+        template.number(100);
+        template.literal("% '#");
+        assert_eq!(Message(vec![Part::Template(template)]), message);
+
+        for (bad, position) in [
+            ("{", 0),
+            ("}", 0),
+            ("{}", 1),
+            ("{count", 0),
+            ("{count, plural}", 14),
+            ("{count, plural, one {#}}", 0),
+            ("{count, plural, some {#} other {#}}", 16),
+            ("{count, plural, =x {#} other {#}}", 17),
+            ("{count, plural, offset:x other {#}}", 23),
+            ("{count, spell}", 8),
+            ("{count, select, other}", 21),
+            ("{count, number, other}", 14),
+            ("a {b, select, other {c '{d}}", 23),
+            ("%{days", 0),
+            ("%{da{ys}", 4),
+            ("%", 0),
+        ] {
+            let error = bad.parse::<Message>().unwrap_err();
+            assert_eq!(position, error.position(), "{}: {}", bad, error);
+        }
+
+        let message: Message = "{n, plural, other {{unit, select, other {#}}}}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            Err(MessageError::MissingArgument {
+                position: 19,
+                name: "unit".into()
+            }),
+            message.check(&Arguments::default().with("n", 1))
+        );
+        assert_eq!(
+            Err(MessageError::ArgumentType {
+                position: 0,
+                name: "n".into(),
+                expected: "a number"
+            }),
+            message.check(&Arguments::default().with("n", "one"))
+        );
+        assert!(message
+            .check(&Arguments::default().with("n", 1).with("unit", Words::Day))
+            .is_ok());
+        assert_eq!(
+            "Missing argument 'unit' at position 19",
+            message
+                .check(&Arguments::default().with("n", 1))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
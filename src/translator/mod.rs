@@ -1,13 +1,80 @@
+//! Translation of the words and sentence patterns formatters emit. A [Translator] maps [Words] to
+//! text and renders [Template]s, and anything implementing [Translate] can stand in for it.
+//!
+//! # Locale files
+//!
+//! Locales are YAML files in `st-locales` mapping each word to its translation. They are layered:
+//! `en-GB` takes each word from `en-GB.yml`, then `en.yml`, then `C.yml`. With the `translation`
+//! feature they are embedded, `LocaleDirectory` reads them from a directory at runtime and can
+//! watch it, and `validate_locale` reports missing, extra and duplicate keys. Besides a word per
+//! key, a locale can give:
+//!
+//! - `language: ru`, which picks the [PluralRules], [OrdinalRules] and [CasingRules]. Forms of a
+//!   unit are keyed by plural category, as in `day_few`, and fall back to `day` and `days`.
+//! - `numbering: arab`, the digits numbers are written in; see [Translator::with_numbering].
+//! - Sentence [Pattern]s such as `past: "il y a %{duration}"` and `quantity: "%{count}%{unit}"`,
+//!   which set the word order. English order is used without them.
+//! - Words prefixed with a [Usage], as in `past.days: Tagen`, used inside that pattern.
+//! - Genders such as `week_gender: feminine`, which [Translator::modifier] agrees with.
+//! - List patterns such as `list_end: "%{0}, %{and} %{1}"`, joining units as [ListStyle] picks.
+//! - `cardinal_<n>` and `ordinal_<n>` rules spelling numbers out, as [Translator::cardinal_in]
+//!   and [Translator::ordinal_in] describe, and suffixes such as `suffix_one: st` for digits.
+//! - Abbreviations such as `hour_short: hr.` and `hour_narrow: h`, for narrower [ListWidth]s.
+//!
+//! Words are written in lowercase, as in the middle of a sentence, and capitalized when rendering
+//! as [Translator::with_capitalization] asks.
+//!
+//! # Other sources
+//!
+//! A [Catalog](crate::translator::Catalog), such as a `FluentCatalog` with the `fluent` feature
+//! or a `GettextCatalog` with `gettext`, builds a translator with [Translator::from_catalog]. A
+//! translator borrows its strings unless it is built with [Translator::from_owned] or converted
+//! with [Translator::into_owned]. [Translator::format_message] fills messages written in a subset
+//! of ICU MessageFormat.
+
+mod catalog;
+#[cfg(feature = "translation")]
+mod directory;
+#[cfg(feature = "fluent")]
+mod fluent;
+#[cfg(feature = "gettext")]
+mod gettext;
 mod loader;
+mod message;
+mod template;
+mod translate;
+#[cfg(feature = "translation")]
+mod validate;
 
+pub use self::catalog::Catalog;
+#[cfg(feature = "translation")]
+pub use self::directory::{LocaleDirectory, LocaleWatcher};
+#[cfg(feature = "fluent")]
+pub use self::fluent::FluentCatalog;
+#[cfg(feature = "gettext")]
+pub use self::gettext::GettextCatalog;
 #[cfg(feature = "translation")]
-use self::loader::*;
-use crate::enums::Words;
+pub use self::loader::*;
+pub use self::message::{Argument, Arguments, Message, MessageError};
+use self::message::{Format, Part, Selector};
+pub use self::template::{NumberForm, Template, Token};
+pub use self::translate::Translate;
+#[cfg(feature = "translation")]
+pub use self::validate::{
+    assert_locales_complete, validate_locale, validate_locales, LocaleReport,
+};
+use crate::enums::{
+    Capitalization, CasingRules, Gender, ListPart, ListStyle, ListWidth, Modifier, NumberingSystem,
+    OrdinalRules, Pattern, PluralCategory, PluralRules, Usage, Words,
+};
+use crate::time_boundary::TimeBoundary;
 #[cfg(not(feature = "translation"))]
 use crate::translation_map;
-use anyhow::{anyhow, Result};
-use std::collections::HashMap;
+use anyhow::Result;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Deref;
 use std::str::FromStr;
+use std::sync::Arc;
 
 #[cfg(feature = "translation")]
 lazy_static::lazy_static! {
@@ -21,30 +88,20 @@ lazy_static::lazy_static! {
     /// standard to American English. If you wish to use other languages, please provided your own
     /// [Translator].
     pub static ref DEFAULT_TRANSLATION: Translator<'static> = Translator::new(translation_map!(
-            (Words::January, "January"),
-            (Words::February, "February"),
-            (Words::March, "March"),
-            (Words::April, "April"),
-            (Words::May, "May"),
-            (Words::June, "June"),
-            (Words::July, "July"),
-            (Words::August, "August"),
-            (Words::September, "September"),
-            (Words::October, "October"),
-            (Words::November, "November"),
-            (Words::December, "December"),
-            (Words::Suffix(0), "th"),
-            (Words::Suffix(1), "st"),
-            (Words::Suffix(2), "nd"),
-            (Words::Suffix(3), "rd"),
-            (Words::Suffix(4), "th"),
-            (Words::Suffix(5), "th"),
-            (Words::Suffix(6), "th"),
-            (Words::Suffix(7), "th"),
-            (Words::Suffix(8), "th"),
-            (Words::Suffix(9), "th"),
-            (Words::Noon, "Noon"),
-            (Words::Midnight, "Midnight"),
+            (Words::January, "january"),
+            (Words::February, "february"),
+            (Words::March, "march"),
+            (Words::April, "april"),
+            (Words::May, "may"),
+            (Words::June, "june"),
+            (Words::July, "july"),
+            (Words::August, "august"),
+            (Words::September, "september"),
+            (Words::October, "october"),
+            (Words::November, "november"),
+            (Words::December, "december"),
+            (Words::Noon, "noon"),
+            (Words::Midnight, "midnight"),
             (Words::PM, "PM"),
             (Words::AM, "AM"),
             (Words::A, "a"),
@@ -53,6 +110,8 @@ lazy_static::lazy_static! {
             (Words::At, "at"),
             (Words::Ago, "ago"),
             (Words::Last, "last"),
+            (Words::Next, "next"),
+            (Words::This, "this"),
             (Words::Year, "year"),
             (Words::Week, "week"),
             (Words::Month, "month"),
@@ -67,24 +126,179 @@ lazy_static::lazy_static! {
             (Words::HourPlural, "hours"),
             (Words::MinutePlural, "minutes"),
             (Words::SecondPlural, "seconds"),
-            (Words::Yesterday, "Yesterday"),
-            (Words::Today, "Today"),
-            (Words::Tomorrow, "Tomorrow"),
-            (Words::Sunday, "Sunday"),
-            (Words::Monday, "Monday"),
-            (Words::Tuesday, "Tuesday"),
-            (Words::Wednesday, "Wednesday"),
-            (Words::Thursday, "Thursday"),
-            (Words::Friday, "Friday"),
-            (Words::Saturday, "Saturday"),
+            (Words::Yesterday, "yesterday"),
+            (Words::Today, "today"),
+            (Words::Tomorrow, "tomorrow"),
+            (Words::Sunday, "sunday"),
+            (Words::Monday, "monday"),
+            (Words::Tuesday, "tuesday"),
+            (Words::Wednesday, "wednesday"),
+            (Words::Thursday, "thursday"),
+            (Words::Friday, "friday"),
+            (Words::Saturday, "saturday"),
             (Words::And, "and"),
-            (Words::FromNow, "from now")
+            (Words::FromNow, "from now"),
+            (Words::Cardinal(0, None), "zero"),
+            (Words::Cardinal(1, None), "one"),
+            (Words::Cardinal(2, None), "two"),
+            (Words::Cardinal(3, None), "three"),
+            (Words::Cardinal(4, None), "four"),
+            (Words::Cardinal(5, None), "five"),
+            (Words::Cardinal(6, None), "six"),
+            (Words::Cardinal(7, None), "seven"),
+            (Words::Cardinal(8, None), "eight"),
+            (Words::Cardinal(9, None), "nine"),
+            (Words::Cardinal(10, None), "ten"),
+            (Words::Cardinal(11, None), "eleven"),
+            (Words::Cardinal(12, None), "twelve"),
+            (Words::Cardinal(13, None), "thirteen"),
+            (Words::Cardinal(14, None), "fourteen"),
+            (Words::Cardinal(15, None), "fifteen"),
+            (Words::Cardinal(16, None), "sixteen"),
+            (Words::Cardinal(17, None), "seventeen"),
+            (Words::Cardinal(18, None), "eighteen"),
+            (Words::Cardinal(19, None), "nineteen"),
+            (Words::Cardinal(20, None), "twenty[->>]"),
+            (Words::Cardinal(30, None), "thirty[->>]"),
+            (Words::Cardinal(40, None), "forty[->>]"),
+            (Words::Cardinal(50, None), "fifty[->>]"),
+            (Words::Cardinal(60, None), "sixty[->>]"),
+            (Words::Cardinal(70, None), "seventy[->>]"),
+            (Words::Cardinal(80, None), "eighty[->>]"),
+            (Words::Cardinal(90, None), "ninety[->>]"),
+            (Words::Cardinal(100, None), "<< hundred[ >>]"),
+            (Words::Cardinal(1_000, None), "<< thousand[ >>]"),
+            (Words::Cardinal(1_000_000, None), "<< million[ >>]"),
+            (Words::Article(Box::new(Words::Hour)), "an"),
+            (Words::Suffix(PluralCategory::One, None), "st"),
+            (Words::Suffix(PluralCategory::Two, None), "nd"),
+            (Words::Suffix(PluralCategory::Few, None), "rd"),
+            (Words::Suffix(PluralCategory::Other, None), "th"),
+            (Words::Ordinal(0, None), "zeroth"),
+            (Words::Ordinal(1, None), "first"),
+            (Words::Ordinal(2, None), "second"),
+            (Words::Ordinal(3, None), "third"),
+            (Words::Ordinal(4, None), "fourth"),
+            (Words::Ordinal(5, None), "fifth"),
+            (Words::Ordinal(6, None), "sixth"),
+            (Words::Ordinal(7, None), "seventh"),
+            (Words::Ordinal(8, None), "eighth"),
+            (Words::Ordinal(9, None), "ninth"),
+            (Words::Ordinal(10, None), "tenth"),
+            (Words::Ordinal(11, None), "eleventh"),
+            (Words::Ordinal(12, None), "twelfth"),
+            (Words::Ordinal(13, None), "thirteenth"),
+            (Words::Ordinal(14, None), "fourteenth"),
+            (Words::Ordinal(15, None), "fifteenth"),
+            (Words::Ordinal(16, None), "sixteenth"),
+            (Words::Ordinal(17, None), "seventeenth"),
+            (Words::Ordinal(18, None), "eighteenth"),
+            (Words::Ordinal(19, None), "nineteenth"),
+            (Words::Ordinal(20, None), "twent[y->>|ieth]"),
+            (Words::Ordinal(30, None), "thirt[y->>|ieth]"),
+            (Words::Ordinal(40, None), "fort[y->>|ieth]"),
+            (Words::Ordinal(50, None), "fift[y->>|ieth]"),
+            (Words::Ordinal(60, None), "sixt[y->>|ieth]"),
+            (Words::Ordinal(70, None), "sevent[y->>|ieth]"),
+            (Words::Ordinal(80, None), "eight[y->>|ieth]"),
+            (Words::Ordinal(90, None), "ninet[y->>|ieth]"),
+            (Words::Ordinal(100, None), "<< hundred[ >>|th]"),
+            (Words::Ordinal(1_000, None), "<< thousand[ >>|th]"),
+            (Words::Ordinal(1_000_000, None), "<< million[ >>|th]"),
+            (Words::Abbreviation(TimeBoundary::Year, ListWidth::Short, None), "yr."),
+            (Words::Abbreviation(TimeBoundary::Month, ListWidth::Short, None), "mo."),
+            (Words::Abbreviation(TimeBoundary::Week, ListWidth::Short, None), "wk."),
+            (Words::Abbreviation(TimeBoundary::Day, ListWidth::Short, None), "day"),
+            (Words::Abbreviation(TimeBoundary::Hour, ListWidth::Short, None), "hr."),
+            (Words::Abbreviation(TimeBoundary::Minute, ListWidth::Short, None), "min."),
+            (Words::Abbreviation(TimeBoundary::Second, ListWidth::Short, None), "sec."),
+            (Words::Abbreviation(TimeBoundary::Year, ListWidth::Narrow, None), "y"),
+            (Words::Abbreviation(TimeBoundary::Month, ListWidth::Narrow, None), "mo"),
+            (Words::Abbreviation(TimeBoundary::Week, ListWidth::Narrow, None), "w"),
+            (Words::Abbreviation(TimeBoundary::Day, ListWidth::Narrow, None), "d"),
+            (Words::Abbreviation(TimeBoundary::Hour, ListWidth::Narrow, None), "h"),
+            (Words::Abbreviation(TimeBoundary::Minute, ListWidth::Narrow, None), "m"),
+            (Words::Abbreviation(TimeBoundary::Second, ListWidth::Narrow, None), "s"),
+            (
+                Words::Abbreviation(TimeBoundary::Day, ListWidth::Short, Some(PluralCategory::Other)),
+                "days"
+            )
     ));
 }
 
 /// Map of translation [Words] to their literal meanings.
 pub type TranslationMap<'a> = HashMap<Words, &'a str>;
 
+/// Map of translation [Words] to literal meanings which are owned and shared, for translators
+/// which must not borrow; see [Translator::from_owned].
+pub type OwnedTranslationMap = HashMap<Words, Arc<str>>;
+
+/// A translation held by a [Translator]: borrowed from the map it was built from, or shared once
+/// the translator owns it. Sharing lets the indexes of a translator hold the same string.
+#[derive(Clone, Debug)]
+enum Text<'a> {
+    Borrowed(&'a str),
+    Shared(Arc<str>),
+}
+
+impl Text<'_> {
+    fn into_owned(self) -> Text<'static> {
+        match self {
+            Self::Borrowed(s) => Text::Shared(s.into()),
+            Self::Shared(s) => Text::Shared(s),
+        }
+    }
+}
+
+impl Deref for Text<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            Self::Borrowed(s) => s,
+            Self::Shared(s) => s,
+        }
+    }
+}
+
+/// Errors from strictly translating a format; see [Translator::format_checked].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TranslationError {
+    /// The format could not be parsed.
+    InvalidFormat(String),
+    /// The format contained terms that could not be translated. Every offending term is listed,
+    /// once, in the order it was first found.
+    Incomplete {
+        /// [Words] which have no translation, even after consulting any fallback.
+        missing: Vec<Words>,
+        /// Terms inside `%{…}` which are not [Words] at all.
+        unknown: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat(e) => f.write_str(e),
+            Self::Incomplete { missing, unknown } => {
+                f.write_str("Incomplete translation")?;
+                if !missing.is_empty() {
+                    let missing: Vec<String> = missing.iter().map(|w| w.to_string()).collect();
+                    write!(f, " (missing: {})", missing.join(", "))?;
+                }
+
+                if !unknown.is_empty() {
+                    write!(f, " (unknown: {})", unknown.join(", "))?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for TranslationError {}
+
 /// The [Translator] provides a format string conversion system that is independent of
 /// strftime/strptime (which means they can both be used) but also is generic enough that it can
 /// incorporate whole translation tables. It is expected that
@@ -93,20 +307,617 @@ pub type TranslationMap<'a> = HashMap<Words, &'a str>;
 ///
 /// If the translation feature is enabled, translations will automatically load based on the user's
 /// locale. See [load_locale](crate::load_locale) for more information.
+///
+/// Words missing from the map translate to nothing, unless a fallback translator is provided with
+/// [Translator::with_fallback]. Use [Translator::format_checked] to find out what is missing
+/// instead.
+///
+/// A translator borrows the strings of its [TranslationMap]. Locales loaded at runtime, from
+/// strings, files or caches, can be owned instead with [Translator::into_owned] or
+/// [Translator::from_owned]; a `Translator<'static>` is [Send] and [Sync], so it can be kept in
+/// application state and shared between threads in an [Arc].
 pub struct Translator<'a> {
-    map: TranslationMap<'a>,
+    map: HashMap<Words, Text<'a>>,
+    patterns: HashMap<Pattern, Template>,
+    variants: HashMap<(Usage, Words), Text<'a>>,
+    genders: HashMap<Words, Gender>,
+    cardinals: BTreeMap<(Option<Usage>, Option<Gender>, u64), Text<'a>>,
+    ordinals: BTreeMap<(Option<Usage>, Option<Gender>, u64), Text<'a>>,
+    numbering: Option<NumberingSystem>,
+    capitalization: Capitalization,
+    fallback: Option<Box<Translator<'a>>>,
 }
 
 impl<'a> Translator<'a> {
-    /// Construct a new translator from a [TranslationMap].
+    /// Construct a new translator from a [TranslationMap]. Any sentence [Pattern]s in the map are
+    /// compiled here; one which cannot be parsed is used as literal text. [Words::Variant]s are
+    /// indexed by [Usage], and [Words::GenderOf] by noun, so rendering can find them without
+    /// allocating; a gender which cannot be parsed is ignored.
     pub fn new(map: TranslationMap<'a>) -> Self {
-        Self { map }
+        Self::from_texts(
+            map.into_iter()
+                .map(|(word, value)| (word, Text::Borrowed(value)))
+                .collect(),
+        )
+    }
+
+    fn from_texts(map: HashMap<Words, Text<'a>>) -> Self {
+        let patterns = map
+            .iter()
+            .filter_map(|(word, value)| match word {
+                Words::Pattern(pattern) => Some((
+                    pattern.clone(),
+                    Template::from_str(value).unwrap_or_else(|_| {
+                        Template::from(vec![Token::Literal(value.to_string().into())])
+                    }),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        let variants = map
+            .iter()
+            .filter_map(|(word, value)| match word {
+                Words::Variant(usage, word) => Some(((*usage, (**word).clone()), value.clone())),
+                _ => None,
+            })
+            .collect();
+
+        let genders = map
+            .iter()
+            .filter_map(|(word, value)| match word {
+                Words::GenderOf(noun) => Some(((**noun).clone(), value.parse().ok()?)),
+                _ => None,
+            })
+            .collect();
+
+        let rules = |ordinal: bool| {
+            map.iter()
+                .filter_map(|(word, value)| {
+                    let (usage, word) = match word {
+                        Words::Variant(usage, word) => (Some(*usage), &**word),
+                        word => (None, word),
+                    };
+
+                    match (word, ordinal) {
+                        (Words::Cardinal(n, gender), false) | (Words::Ordinal(n, gender), true) => {
+                            Some(((usage, *gender, *n), value.clone()))
+                        }
+                        _ => None,
+                    }
+                })
+                .collect()
+        };
+        let cardinals = rules(false);
+        let ordinals = rules(true);
+
+        Self {
+            map,
+            patterns,
+            variants,
+            genders,
+            cardinals,
+            ordinals,
+            numbering: None,
+            capitalization: Capitalization::default(),
+            fallback: None,
+        }
+    }
+
+    /// Build a translator from several maps, the first taking precedence: each word is looked up
+    /// in every map in turn until one has it. This is useful for layering a regional variant over
+    /// its language, or a few application-specific words over a whole locale. [None] is returned
+    /// if there are no maps.
+    pub fn layered(maps: impl IntoIterator<Item = TranslationMap<'a>>) -> Option<Self> {
+        maps.into_iter()
+            .map(Self::new)
+            .reduce(|translator, fallback| translator.with_fallback(fallback))
+    }
+
+    /// Consult another translator for any words missing from this one, such as a more complete
+    /// locale. If this translator already has a fallback, the new one is consulted last.
+    pub fn with_fallback(mut self, fallback: Translator<'a>) -> Self {
+        self.fallback = Some(Box::new(match self.fallback.take() {
+            Some(existing) => existing.with_fallback(fallback),
+            None => fallback,
+        }));
+        self
+    }
+
+    /// Layer a map over this translator, so that its words take precedence.
+    pub fn with_overrides(self, map: TranslationMap<'a>) -> Self {
+        Self::new(map).with_fallback(self)
+    }
+
+    /// Write numbers in a [NumberingSystem] regardless of the one the locale declares. Use
+    /// [NumberingSystem::Latn] to force ASCII digits.
+    pub fn with_numbering(mut self, numbering: NumberingSystem) -> Self {
+        self.numbering = Some(numbering);
+        self
+    }
+
+    /// Capitalize rendered output for the [Capitalization] context it is placed in, following the
+    /// locale's [CasingRules]. Output is assumed to be in the middle of a sentence otherwise. See
+    /// [Translator::render_capitalized] to choose the context for a single template.
+    pub fn with_capitalization(mut self, capitalization: Capitalization) -> Self {
+        self.capitalization = capitalization;
+        self
+    }
+
+    /// Construct a translator from the YAML contents of a locale file, the same format as the
+    /// files in `st-locales`.
+    #[cfg(feature = "translation")]
+    pub fn from_yaml(yaml: &'a str) -> Result<Self> {
+        Ok(Self::new(serde_yaml::from_str(yaml)?))
+    }
+
+    /// Construct a translator from a [Catalog], such as a Fluent or gettext catalog, with its
+    /// plurals expanded for the language of the catalog. See [Catalog::translation_map].
+    pub fn from_catalog(catalog: &'a (impl Catalog + ?Sized)) -> Self {
+        Self::new(catalog.translation_map())
+    }
+
+    /// Construct a translator which owns its translations, so it does not borrow from the map.
+    pub fn from_owned(map: OwnedTranslationMap) -> Translator<'static> {
+        Translator::from_texts(
+            map.into_iter()
+                .map(|(word, value)| (word, Text::Shared(value)))
+                .collect(),
+        )
+    }
+
+    /// Construct a translator which owns its translations from the YAML contents of a locale file,
+    /// so the contents need not outlive it. See [Translator::from_yaml].
+    #[cfg(feature = "translation")]
+    pub fn from_yaml_owned(yaml: &str) -> Result<Translator<'static>> {
+        let map: HashMap<Words, String> = serde_yaml::from_str(yaml)?;
+        Ok(Translator::from_owned(
+            map.into_iter()
+                .map(|(word, value)| (word, value.into()))
+                .collect(),
+        ))
+    }
+
+    /// Copy every borrowed translation, in this translator and its fallbacks, so that it no
+    /// longer borrows from the maps it was built from. This is how a translator from a
+    /// `LocaleDirectory` or a [Catalog] can outlive it.
+    pub fn into_owned(self) -> Translator<'static> {
+        fn owned<'a, K, C: FromIterator<(K, Text<'static>)>>(
+            map: impl IntoIterator<Item = (K, Text<'a>)>,
+        ) -> C {
+            map.into_iter()
+                .map(|(key, value)| (key, value.into_owned()))
+                .collect()
+        }
+
+        Translator {
+            map: owned(self.map),
+            patterns: self.patterns,
+            variants: owned(self.variants),
+            genders: self.genders,
+            cardinals: owned(self.cardinals),
+            ordinals: owned(self.ordinals),
+            numbering: self.numbering,
+            capitalization: self.capitalization,
+            fallback: self
+                .fallback
+                .map(|fallback| Box::new(fallback.into_owned())),
+        }
     }
 
     /// Given a word, translate it to the literal meaning, if it exists. Otherwise, return [None].
+    /// Every layer is consulted for the word before its [Words::fallback] is tried.
+    ///
+    /// The translation borrows from the translator, as an owned translator holds its words itself;
+    /// it used to be an `Option<&'a str>` borrowed from the [TranslationMap]. A translation which
+    /// must outlive the translator can be looked up in the map instead.
     #[inline]
-    pub fn translate(&self, s: &Words) -> Option<&'a str> {
-        self.map.get(s).copied()
+    pub fn translate(&self, s: &Words) -> Option<&str> {
+        match s {
+            Words::Variant(usage, word) => self.translate_in(word, Some(*usage)),
+            _ => self.lookup(s).or_else(|| self.translate(&s.fallback()?)),
+        }
+    }
+
+    /// Translate a word as it appears in a [Usage]. The most specific form the locale has is used:
+    /// for `day_few` in the past, that is `past.day_few`, then `day_few`, then `past.days` and
+    /// finally `days`.
+    pub fn translate_in(&self, s: &Words, usage: Option<Usage>) -> Option<&str> {
+        let Some(usage) = usage else {
+            return self.translate(s);
+        };
+
+        self.lookup_variant(usage, s)
+            .or_else(|| self.lookup(s))
+            .or_else(|| self.translate_in(&s.fallback()?, Some(usage)))
+    }
+
+    fn lookup_variant(&self, usage: Usage, s: &Words) -> Option<&str> {
+        match self.variants.get(&(usage, s.clone())) {
+            Some(value) => Some(value),
+            None => self.fallback.as_ref()?.lookup_variant(usage, s),
+        }
+    }
+
+    fn lookup(&self, s: &Words) -> Option<&str> {
+        match self.map.get(s) {
+            Some(value) => Some(value),
+            None => self.fallback.as_ref()?.lookup(s),
+        }
+    }
+
+    /// The compiled sentence [Pattern] of the locale, after consulting every layer and the
+    /// [Words::fallback]. [None] means the [Pattern::default_template] applies.
+    pub fn pattern(&self, pattern: &Pattern) -> Option<&Template> {
+        if let Pattern::Modified(modifier, gender) = pattern {
+            return [
+                (*modifier, *gender),
+                (*modifier, None),
+                (None, *gender),
+                (None, None),
+            ]
+            .into_iter()
+            .find_map(|(modifier, gender)| {
+                self.lookup_pattern(&Pattern::Modified(modifier, gender))
+            });
+        }
+
+        self.lookup_pattern(pattern)
+            .or_else(|| match Words::Pattern(pattern.clone()).fallback() {
+                Some(Words::Pattern(fallback)) => self.pattern(&fallback),
+                _ => None,
+            })
+    }
+
+    /// The [Pattern] for a number of a unit at a [ListWidth]: the [Pattern::PluralQuantity] for
+    /// the count's category if the locale has one, otherwise [Pattern::UnitQuantity] when wide and
+    /// [Pattern::AbbreviatedQuantity] when not.
+    fn quantity_pattern(&self, count: i64, unit: &TimeBoundary, width: ListWidth) -> Pattern {
+        let plural = Pattern::PluralQuantity(width, self.plural_rules().category(count));
+        if self.pattern(&plural).is_some() {
+            return plural;
+        }
+
+        match width {
+            ListWidth::Wide => Pattern::UnitQuantity(unit.clone()),
+            _ => Pattern::AbbreviatedQuantity(width),
+        }
+    }
+
+    /// The [Pattern] for a noun with a [Modifier]: its [Pattern::NounModified] if the locale has
+    /// one, otherwise [Pattern::Modified] for the noun's [Gender].
+    fn modified_pattern(&self, modifier: Modifier, noun: &Words) -> Pattern {
+        let specific = Pattern::NounModified(modifier, Box::new(noun.clone()));
+        match self.lookup_pattern(&specific) {
+            Some(_) => specific,
+            None => Pattern::Modified(Some(modifier), self.gender(noun)),
+        }
+    }
+
+    fn lookup_pattern(&self, pattern: &Pattern) -> Option<&Template> {
+        match self.patterns.get(pattern) {
+            Some(template) => Some(template),
+            None => self.fallback.as_ref()?.lookup_pattern(pattern),
+        }
+    }
+
+    /// The grammatical [Gender] of a noun, if the locale declares one.
+    pub fn gender(&self, noun: &Words) -> Option<Gender> {
+        match self.genders.get(noun) {
+            Some(gender) => Some(*gender),
+            None => self.fallback.as_ref()?.gender(noun),
+        }
+    }
+
+    /// Translate a [Modifier] in the form agreeing with a noun, such as "dernière" for "semaine".
+    pub fn modifier(&self, modifier: Modifier, noun: &Words) -> Option<&str> {
+        match self.gender(noun) {
+            Some(gender) => self.translate(&Words::Agreeing(modifier, gender)),
+            None => self.translate(&modifier.into()),
+        }
+    }
+
+    /// The plural rules of the locale, chosen by its [Words::Language]. English rules are used if
+    /// the locale does not name its language.
+    pub fn plural_rules(&self) -> PluralRules {
+        self.lookup(&Words::Language)
+            .map(PluralRules::for_language)
+            .unwrap_or_default()
+    }
+
+    /// The ordinal rules of the locale, chosen by its [Words::Language], which pick the
+    /// [Words::Suffix] of an ordinal. English rules are used if the locale does not name its
+    /// language.
+    pub fn ordinal_rules(&self) -> OrdinalRules {
+        OrdinalRules::for_language(self.lookup(&Words::Language).unwrap_or_default())
+    }
+
+    /// The casing rules of the locale, chosen by its [Words::Language], which capitalize words
+    /// such as German nouns when rendering. English rules are used if the locale does not name
+    /// its language.
+    pub fn casing_rules(&self) -> CasingRules {
+        self.lookup(&Words::Language)
+            .map(CasingRules::for_language)
+            .unwrap_or_default()
+    }
+
+    /// The [NumberingSystem] numbers are written in: the one given to
+    /// [Translator::with_numbering], else the locale's [Words::Numbering], else ASCII digits. A
+    /// numbering system which cannot be parsed is ignored.
+    pub fn numbering(&self) -> NumberingSystem {
+        self.lookup_numbering().unwrap_or_default()
+    }
+
+    fn lookup_numbering(&self) -> Option<NumberingSystem> {
+        self.numbering
+            .or_else(|| self.map.get(&Words::Numbering)?.parse().ok())
+            .or_else(|| self.fallback.as_ref()?.lookup_numbering())
+    }
+
+    /// The indefinite article of a unit or other noun, from its [Words::Article] or else
+    /// [Words::A]. An article of `a` or `an` names that word, so `hour_article: an` gives each
+    /// locale's own translation of [Words::An].
+    pub fn article(&self, noun: &Words) -> Option<&str> {
+        self.article_in(noun, None)
+    }
+
+    /// Like [Translator::article], for a noun as it appears in a [Usage].
+    pub fn article_in(&self, noun: &Words, usage: Option<Usage>) -> Option<&str> {
+        let article = self.translate_in(&Words::Article(Box::new(noun.clone())), usage)?;
+        match Words::from_str(article) {
+            Ok(word @ (Words::A | Words::An)) => self.translate_in(&word, usage).or(Some(article)),
+            _ => Some(article),
+        }
+    }
+
+    /// Spell out a number in words with the locale's cardinal rules. [None] is returned if the
+    /// locale has none, or the number is negative. See [Translator::cardinal_in].
+    pub fn cardinal(&self, number: i64) -> Option<String> {
+        self.cardinal_in(number, None, None)
+    }
+
+    /// Spell out a number agreeing with a noun of a [Gender], as it appears in a [Usage].
+    ///
+    /// Each [Words::Cardinal] rule covers the numbers from its own up to the next rule. Within
+    /// it, `<<` is replaced with the number divided by the largest power of ten not above the
+    /// rule, `>>` with the remainder, and text in `[…]` is left out when the remainder is zero:
+    /// with `cardinal_20: "twenty[->>]"` and `cardinal_100: "<< hundred[ >>]"`, 345 is spelled
+    /// "three hundred forty-five". Rules for the gender and usage are preferred over plain ones
+    /// of the same number, and the remainder agrees with the same gender. The quotient agrees with
+    /// a gender named between its brackets, as in `<feminine<`, and `$(one{…}few{…}other{…})$`
+    /// gives the form for its [PluralCategory]: with
+    /// `cardinal_2000: "<feminine< $(one{тысяча}few{тысячи}other{тысяч})$[ >>]"`, 21000 is
+    /// spelled "двадцать одна тысяча" and 22000 "двадцать две тысячи".
+    ///
+    /// The rules are taken whole from the most specific layer which has any, so that a locale
+    /// without them does not spell numbers in the language of its fallback.
+    pub fn cardinal_in(
+        &self,
+        number: i64,
+        gender: Option<Gender>,
+        usage: Option<Usage>,
+    ) -> Option<String> {
+        let number = u64::try_from(number).ok()?;
+        let mut s = String::new();
+        self.cardinal_rules()?
+            .spell(number, gender, usage, false, &mut s)?;
+        Some(s)
+    }
+
+    fn cardinal_rules(&self) -> Option<&Self> {
+        match self.cardinals.is_empty() {
+            true => self.fallback.as_ref()?.cardinal_rules(),
+            false => Some(self),
+        }
+    }
+
+    /// Spell out an ordinal in words with the locale's ordinal rules, such as "twenty-third".
+    /// [None] is returned if the locale has none, or the number is negative. See
+    /// [Translator::ordinal_in].
+    pub fn ordinal(&self, number: i64) -> Option<String> {
+        self.ordinal_in(number, None, None)
+    }
+
+    /// Spell out an ordinal agreeing with a noun of a [Gender], as it appears in a [Usage].
+    ///
+    /// [Words::Ordinal] rules work as [Translator::cardinal_in] describes, except that `<<` is
+    /// spelled as a cardinal and `>>` as an ordinal, and `[…|…]` gives the text to use when the
+    /// remainder is zero after the `|`: with `ordinal_20: "twent[y->>|ieth]"`, 20 is "twentieth"
+    /// and 23 is "twenty-third". The rules come from the most specific layer with any cardinal or
+    /// ordinal rules, so a locale which only spells cardinals writes ordinals in digits.
+    pub fn ordinal_in(
+        &self,
+        number: i64,
+        gender: Option<Gender>,
+        usage: Option<Usage>,
+    ) -> Option<String> {
+        let number = u64::try_from(number).ok()?;
+        let mut s = String::new();
+        self.ordinal_rules_layer()?
+            .spell(number, gender, usage, true, &mut s)?;
+        Some(s)
+    }
+
+    fn ordinal_rules_layer(&self) -> Option<&Self> {
+        match self.ordinals.is_empty() && self.cardinals.is_empty() {
+            true => self.fallback.as_ref()?.ordinal_rules_layer(),
+            false => Some(self),
+        }
+    }
+
+    /// The suffix of an ordinal written in digits, such as "rd" for 23, chosen by the
+    /// [Translator::ordinal_rules] and agreeing with a [Gender].
+    pub fn suffix(&self, number: i64, gender: Option<Gender>) -> Option<&str> {
+        self.suffix_in(number, gender, None)
+    }
+
+    /// Like [Translator::suffix], for an ordinal as it appears in a [Usage].
+    pub fn suffix_in(
+        &self,
+        number: i64,
+        gender: Option<Gender>,
+        usage: Option<Usage>,
+    ) -> Option<&str> {
+        let category = self.ordinal_rules().category(number);
+        self.translate_in(&Words::Suffix(category, gender), usage)
+    }
+
+    fn spell(
+        &self,
+        number: u64,
+        gender: Option<Gender>,
+        usage: Option<Usage>,
+        ordinal: bool,
+        s: &mut String,
+    ) -> Option<()> {
+        let rules = match ordinal {
+            true => &self.ordinals,
+            false => &self.cardinals,
+        };
+
+        let candidates = [(usage, gender), (usage, None), (None, gender), (None, None)];
+        let (base, rule) = candidates
+            .into_iter()
+            .filter_map(|(usage, gender)| {
+                rules
+                    .range((usage, gender, 0)..=(usage, gender, number))
+                    .next_back()
+                    .map(|((_, _, base), rule)| (*base, &**rule))
+            })
+            .reduce(|best, next| if next.0 > best.0 { next } else { best })?;
+
+        let divisor = 10u64.pow(base.checked_ilog10().unwrap_or(0));
+        let (quotient, remainder) = (number / divisor, number % divisor);
+
+        let mut write = |mut text: &str| {
+            while let Some(at) = text.find(['<', '>', '$']) {
+                s.push_str(&text[..at]);
+                text = &text[at..];
+                if let Some((name, rest)) = text[1..].split_once('<').filter(|(name, _)| {
+                    text.starts_with('<') && name.bytes().all(|b| b.is_ascii_lowercase())
+                }) {
+                    // a rule below ten has nothing to divide
+                    if quotient == number {
+                        return None;
+                    }
+
+                    let gender = match name {
+                        "" => None,
+                        name => Some(name.parse().ok()?),
+                    };
+                    self.cardinal_rules()?
+                        .spell(quotient, gender, usage, false, s)?;
+                    text = rest;
+                } else if let Some(rest) = text.strip_prefix(">>") {
+                    if remainder == number {
+                        return None;
+                    }
+
+                    self.spell(remainder, gender, usage, ordinal, s)?;
+                    text = rest;
+                } else if let Some((forms, rest)) = text
+                    .strip_prefix("$(")
+                    .and_then(|forms| forms.split_once(")$"))
+                {
+                    let category = self.plural_rules().category(quotient as i64);
+                    s.push_str(plural_form(forms, category)?);
+                    text = rest;
+                } else {
+                    s.push_str(&text[..1]);
+                    text = &text[1..];
+                }
+            }
+
+            s.push_str(text);
+            Some(())
+        };
+
+        let mut rest = rule;
+        while let Some((text, optional)) = rest.split_once('[') {
+            write(text)?;
+            let (inner, tail) = optional.split_once(']').unwrap_or((optional, ""));
+            let (nonzero, zero) = inner.split_once('|').unwrap_or((inner, ""));
+            write(if remainder == 0 { zero } else { nonzero })?;
+            rest = tail;
+        }
+
+        write(rest)
+    }
+
+    /// Translate a number of a unit, choosing the form of the unit from the
+    /// [Translator::plural_rules].
+    pub fn quantity(&self, count: i64, unit: &TimeBoundary) -> Option<&str> {
+        self.quantity_in(count, unit, None)
+    }
+
+    /// Like [Translator::quantity], for a unit as it appears in a [Usage].
+    pub fn quantity_in(
+        &self,
+        count: i64,
+        unit: &TimeBoundary,
+        usage: Option<Usage>,
+    ) -> Option<&str> {
+        self.translate_in(
+            &Words::PluralForm(unit.clone(), self.plural_rules().category(count)),
+            usage,
+        )
+    }
+
+    /// Translate a number of a unit abbreviated to a [ListWidth], such as "hr." for 2 hours when
+    /// short. The form for the count's [PluralCategory] is preferred, then the plain
+    /// [Words::Abbreviation], from the most specific layer with either; narrow abbreviations fall
+    /// back to short ones, and short ones to [Translator::quantity].
+    pub fn abbreviation(&self, count: i64, unit: &TimeBoundary, width: ListWidth) -> Option<&str> {
+        self.abbreviation_in(count, unit, width, None)
+    }
+
+    /// Like [Translator::abbreviation], for a unit as it appears in a [Usage].
+    pub fn abbreviation_in(
+        &self,
+        count: i64,
+        unit: &TimeBoundary,
+        width: ListWidth,
+        usage: Option<Usage>,
+    ) -> Option<&str> {
+        let category = self.plural_rules().category(count);
+        let mut width = width;
+        loop {
+            if width == ListWidth::Wide {
+                return self.quantity_in(count, unit, usage);
+            }
+
+            if let Some(abbreviation) = self.lookup_abbreviation(unit, width, category, usage) {
+                return Some(abbreviation);
+            }
+
+            width = match width {
+                ListWidth::Narrow => ListWidth::Short,
+                _ => ListWidth::Wide,
+            };
+        }
+    }
+
+    fn lookup_abbreviation(
+        &self,
+        unit: &TimeBoundary,
+        width: ListWidth,
+        category: PluralCategory,
+        usage: Option<Usage>,
+    ) -> Option<&str> {
+        [Some(category), None]
+            .into_iter()
+            .map(|category| Words::Abbreviation(unit.clone(), width, category))
+            .find_map(|word| {
+                usage
+                    .and_then(|usage| self.variants.get(&(usage, word.clone())))
+                    .or_else(|| self.map.get(&word))
+                    .map(|value| &**value)
+            })
+            .or_else(|| {
+                self.fallback
+                    .as_ref()?
+                    .lookup_abbreviation(unit, width, category, usage)
+            })
     }
 
     /// Given a format, parse it and return the literal meaning. Formats start with %{, contain a
@@ -117,79 +928,627 @@ impl<'a> Translator<'a> {
     /// If any of these terms are violated, you will get an [Err]. Otherwise, your string will be
     /// translated.
     pub fn format(&self, format: &str) -> Result<String> {
-        let mut in_match = false;
-        let mut in_brace = false;
+        let mut s = String::new();
+        self.format_into(format, &mut s)?;
+        Ok(s)
+    }
+
+    /// Like [Translator::format], but writes the result into a [std::fmt::Write] instead of
+    /// allocating a new string.
+    pub fn format_into<W: std::fmt::Write>(&self, format: &str, s: &mut W) -> Result<()> {
+        Ok(self.render_into(&Template::from_str(format)?, s)?)
+    }
+
+    /// Like [Translator::format], but fails with a [TranslationError] listing every term that
+    /// could not be translated, instead of translating them to nothing.
+    pub fn format_checked(&self, format: &str) -> Result<String, TranslationError> {
+        let template = Template::from_str(format)
+            .map_err(|e| TranslationError::InvalidFormat(e.to_string()))?;
+        self.render_checked(&template)
+    }
+
+    /// Like [Translator::render], but fails with a [TranslationError] listing every term that
+    /// could not be translated, instead of translating them to nothing.
+    pub fn render_checked(&self, template: &Template) -> Result<String, TranslationError> {
+        let mut missing = Vec::new();
+        let mut unknown = Vec::new();
+        self.check(template, &[], None, &mut missing, &mut unknown);
+
+        if missing.is_empty() && unknown.is_empty() {
+            Ok(self.render(template))
+        } else {
+            Err(TranslationError::Incomplete { missing, unknown })
+        }
+    }
+
+    /// Parse a [Message] and fill it with typed [Arguments], in the locale's plural rules and
+    /// numbering system: with `"{count, plural, one {# day} other {# days}}"`, a `count` of 2 is
+    /// "2 days". `%{word}` terms are translated as in [Translator::format].
+    ///
+    /// A malformed message, or a missing or mistyped argument, is an [Err] giving the position of
+    /// the problem.
+    pub fn format_message(
+        &self,
+        message: &str,
+        arguments: &Arguments,
+    ) -> Result<String, MessageError> {
+        self.render_message(&Message::from_str(message)?, arguments)
+    }
+
+    /// Fill a compiled [Message] with typed [Arguments]. See [Translator::format_message].
+    pub fn render_message(
+        &self,
+        message: &Message,
+        arguments: &Arguments,
+    ) -> Result<String, MessageError> {
+        message.check(arguments)?;
 
         let mut s = String::new();
-        let mut cap = String::new();
-
-        for ch in format.chars() {
-            match ch {
-                '%' => {
-                    if in_match && !in_brace {
-                        s.push(ch);
-                        in_match = false;
-                    } else {
-                        if in_brace || in_match {
-                            return Err(anyhow!("Invalid format (format attempted within format)"));
-                        }
+        // writing to a string never fails
+        self.write_message(message, arguments, &mut s).unwrap();
+        Ok(s)
+    }
+
+    /// Like [Translator::render_message], but writes the result into a [std::fmt::Write].
+    pub fn render_message_into<W: std::fmt::Write>(
+        &self,
+        message: &Message,
+        arguments: &Arguments,
+        s: &mut W,
+    ) -> Result<()> {
+        message.check(arguments)?;
+        Ok(self.write_message(message, arguments, s)?)
+    }
+
+    fn write_message<W: std::fmt::Write>(
+        &self,
+        message: &Message,
+        arguments: &Arguments,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        if !self.capitalization.capitalizes_first() {
+            return self.write_message_in(message, arguments, None, s);
+        }
+
+        let mut s = CapitalizeFirst {
+            inner: s,
+            rules: self.casing_rules(),
+            pending: true,
+        };
+        self.write_message_in(message, arguments, None, &mut s)
+    }
+
+    /// Write a checked message. `pound` is the number `#` writes within a plural case.
+    fn write_message_in<W: std::fmt::Write>(
+        &self,
+        message: &Message,
+        arguments: &Arguments,
+        pound: Option<i64>,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        for part in &message.0 {
+            let (name, format) = match part {
+                Part::Template(template) => {
+                    self.render_in(template, None, s)?;
+                    continue;
+                }
+                Part::Pound => {
+                    self.numbering()
+                        .write_number(pound.unwrap_or_default(), s)?;
+                    continue;
+                }
+                Part::Placeholder(name, _, format) => (name, format),
+            };
+
+            let Some(argument) = arguments.get(name) else {
+                continue;
+            };
 
-                        in_match = true;
-                        in_brace = false;
+            match (format, argument) {
+                (Format::Plain, Argument::Text(text)) => s.write_str(text)?,
+                (Format::Plain, Argument::Word(word)) => {
+                    self.write_word(word, self.translate(word).unwrap_or_default(), s)?
+                }
+                (Format::Plain, Argument::Template(template)) => {
+                    self.render_in(template, None, s)?
+                }
+                (Format::Plain | Format::Number, Argument::Number(n)) => {
+                    self.numbering().write_number(*n, s)?
+                }
+                (Format::Spellout, Argument::Number(n)) => match self.cardinal(*n) {
+                    Some(words) => s.write_str(&words)?,
+                    None => self.numbering().write_number(*n, s)?,
+                },
+                (Format::Ordinal, Argument::Number(n)) => {
+                    self.numbering().write_number(*n, s)?;
+                    s.write_str(self.suffix(*n, None).unwrap_or_default())?
+                }
+                (
+                    Format::Plural {
+                        ordinal,
+                        offset,
+                        cases,
+                    },
+                    Argument::Number(n),
+                ) => {
+                    // ruled out by Message::check
+                    let Some(offset) = n.checked_sub(*offset) else {
+                        continue;
+                    };
+
+                    let category = match ordinal {
+                        true => self.ordinal_rules().category(offset),
+                        false => self.plural_rules().category(offset),
+                    };
+
+                    let case = cases
+                        .iter()
+                        .find(|(selector, _)| *selector == Selector::Exact(*n))
+                        .or_else(|| {
+                            [category, PluralCategory::Other]
+                                .into_iter()
+                                .find_map(|category| {
+                                    cases.iter().find(|(selector, _)| {
+                                        *selector == Selector::Category(category)
+                                    })
+                                })
+                        });
+
+                    if let Some((_, case)) = case {
+                        self.write_message_in(case, arguments, Some(offset), s)?
                     }
                 }
-                '{' => {
-                    if !in_match {
-                        s.push(ch);
-                    } else {
-                        if in_brace {
-                            return Err(anyhow!(
-                                "Invalid format (open brace attempted within open brace)"
-                            ));
-                        }
+                (Format::Select(cases), Argument::Text(key)) => {
+                    self.write_selected(cases, key, arguments, pound, s)?
+                }
+                (Format::Select(cases), Argument::Word(word)) => {
+                    self.write_selected(cases, &word.to_string(), arguments, pound, s)?
+                }
+                // ruled out by Message::check
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the `select` case of a key, else its `other` case.
+    fn write_selected<W: std::fmt::Write>(
+        &self,
+        cases: &[(String, Message)],
+        key: &str,
+        arguments: &Arguments,
+        pound: Option<i64>,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        let case = [key, "other"]
+            .into_iter()
+            .find_map(|key| cases.iter().find(|(case, _)| case == key));
+
+        match case {
+            Some((_, case)) => self.write_message_in(case, arguments, pound, s),
+            None => Ok(()),
+        }
+    }
+
+    fn check(
+        &self,
+        template: &Template,
+        slots: &[&str],
+        usage: Option<Usage>,
+        missing: &mut Vec<Words>,
+        unknown: &mut Vec<String>,
+    ) {
+        for token in template.iter() {
+            match token {
+                Token::Word(word) if self.translate_in(word, usage).is_none() => {
+                    push_unique(missing, word.clone())
+                }
+                Token::Quantity(count, unit, form) => {
+                    let noun = Words::from(unit.clone());
+                    if *form == NumberForm::Article && self.article_in(&noun, usage).is_none() {
+                        push_unique(missing, Words::A)
+                    }
 
-                        in_brace = true;
+                    if self.quantity_in(*count, unit, usage).is_none() {
+                        let category = self.plural_rules().category(*count);
+                        push_unique(missing, Words::PluralForm(unit.clone(), category))
                     }
+
+                    let pattern = self.quantity_pattern(*count, unit, ListWidth::Wide);
+                    self.check_pattern(&pattern, missing, unknown);
                 }
-                '}' => {
-                    if !in_match {
-                        s.push(ch);
-                    } else {
-                        if !in_brace {
-                            return Err(anyhow!(
-                                "Invalid format (close brace attempted outside open brace)"
-                            ));
-                        }
+                Token::Abbreviated(count, unit, width) => {
+                    if self.abbreviation_in(*count, unit, *width, usage).is_none() {
+                        let category = self.plural_rules().category(*count);
+                        push_unique(missing, Words::PluralForm(unit.clone(), category))
+                    }
 
-                        in_brace = false;
-                        in_match = false;
+                    let pattern = self.quantity_pattern(*count, unit, *width);
+                    self.check_pattern(&pattern, missing, unknown);
+                }
+                Token::Ordinal(_, Some(unit), _) => {
+                    let noun = Words::from(unit.clone());
+                    if self.translate_in(&noun, usage).is_none() {
+                        push_unique(missing, noun)
+                    }
 
-                        if let Some(word) = &Words::from_str(&cap).ok() {
-                            s += self.translate(&word).unwrap_or_default();
-                        }
+                    let pattern = Pattern::UnitOrdinal(unit.clone());
+                    self.check_pattern(&pattern, missing, unknown);
+                }
+                Token::Ordinal(_, None, _) => {
+                    self.check_pattern(&Pattern::DayOfMonth, missing, unknown);
+                }
+                Token::Modified(modifier, noun) => {
+                    if self.modifier(*modifier, noun).is_none() {
+                        push_unique(missing, (*modifier).into())
+                    }
 
-                        cap = String::new();
+                    if self.translate_in(noun, usage).is_none() {
+                        push_unique(missing, noun.clone())
                     }
+
+                    let pattern = self.modified_pattern(*modifier, noun);
+                    self.check_pattern(&pattern, missing, unknown);
                 }
-                _ => {
-                    if in_brace && in_match {
-                        cap.push(ch);
-                    } else {
-                        s.push(ch);
+                Token::List(style, items) => {
+                    for (x, item) in items.iter().enumerate() {
+                        self.check(item, slots, usage, missing, unknown);
+                        let part = if x == 0 {
+                            ListPart::Start
+                        } else {
+                            ListPart::Middle
+                        };
+
+                        if let Some(part) = part.joining(items.len() - x) {
+                            self.check_pattern(&Pattern::List(*style, part), missing, unknown);
+                        }
                     }
                 }
+                Token::Phrase(pattern, inner) => {
+                    self.check_pattern(pattern, missing, unknown);
+                    self.check(inner, slots, pattern.usage(), missing, unknown);
+                }
+                Token::Unknown(term) if !slots.contains(&term.as_str()) => {
+                    push_unique(unknown, term.clone())
+                }
+                _ => {}
             }
         }
+    }
 
-        if in_brace {
-            return Err(anyhow!("Invalid format (unclosed brace)"));
+    fn check_pattern(
+        &self,
+        pattern: &Pattern,
+        missing: &mut Vec<Words>,
+        unknown: &mut Vec<String>,
+    ) {
+        match self.pattern(pattern) {
+            Some(template) => self.check(template, pattern.slots(), None, missing, unknown),
+            None => self.check(
+                &pattern.default_template(),
+                pattern.slots(),
+                None,
+                missing,
+                unknown,
+            ),
         }
+    }
+
+    /// Translate a compiled [Template]. Unlike [Translator::format], no parsing is involved.
+    pub fn render(&self, template: &Template) -> String {
+        let mut s = String::new();
+        // writing to a string never fails
+        self.render_into(template, &mut s).unwrap();
+        s
+    }
 
-        if in_match {
-            return Err(anyhow!("Invalid format (incomplete match)"));
+    /// Like [Translator::render], but writes the result into a [std::fmt::Write].
+    pub fn render_into<W: std::fmt::Write>(
+        &self,
+        template: &Template,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        self.render_capitalized_into(template, self.capitalization, s)
+    }
+
+    /// Like [Translator::render], for output placed in a [Capitalization] context instead of the
+    /// one given to [Translator::with_capitalization]: "in 2 days" is "In 2 days" at the beginning
+    /// of a sentence.
+    pub fn render_capitalized(
+        &self,
+        template: &Template,
+        capitalization: Capitalization,
+    ) -> String {
+        let mut s = String::new();
+        // writing to a string never fails
+        self.render_capitalized_into(template, capitalization, &mut s)
+            .unwrap();
+        s
+    }
+
+    /// Like [Translator::render_capitalized], but writes the result into a [std::fmt::Write].
+    pub fn render_capitalized_into<W: std::fmt::Write>(
+        &self,
+        template: &Template,
+        capitalization: Capitalization,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        if !capitalization.capitalizes_first() {
+            return self.render_in(template, None, s);
         }
 
-        Ok(s)
+        let mut s = CapitalizeFirst {
+            inner: s,
+            rules: self.casing_rules(),
+            pending: true,
+        };
+        self.render_in(template, None, &mut s)
+    }
+
+    fn render_in<W: std::fmt::Write>(
+        &self,
+        template: &Template,
+        usage: Option<Usage>,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        for token in template.iter() {
+            self.render_token(token, usage, s)?;
+        }
+
+        Ok(())
+    }
+
+    fn render_token<W: std::fmt::Write>(
+        &self,
+        token: &Token,
+        usage: Option<Usage>,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        match token {
+            Token::Literal(text) => s.write_str(text),
+            Token::Word(word) => {
+                self.write_word(word, self.translate_in(word, usage).unwrap_or_default(), s)
+            }
+            Token::Unknown(_) => Ok(()),
+            Token::Number(number) => self.numbering().write_number(*number, s),
+            Token::Quantity(count, unit, form) => self.render_pattern(
+                &self.quantity_pattern(*count, unit, ListWidth::Wide),
+                s,
+                |slot, s| match slot {
+                    "count" => Some(self.render_count(*count, unit, *form, usage, s)),
+                    "unit" => Some(self.write_word(
+                        &unit.clone().into(),
+                        self.quantity_in(*count, unit, usage).unwrap_or_default(),
+                        s,
+                    )),
+                    _ => None,
+                },
+            ),
+            Token::Abbreviated(count, unit, width) => {
+                let pattern = self.quantity_pattern(*count, unit, *width);
+                self.render_pattern(&pattern, s, |slot, s| match slot {
+                    "count" => Some(self.numbering().write_number(*count, s)),
+                    "unit" => Some(
+                        self.write_word(
+                            &unit.clone().into(),
+                            self.abbreviation_in(*count, unit, *width, usage)
+                                .unwrap_or_default(),
+                            s,
+                        ),
+                    ),
+                    _ => None,
+                })
+            }
+            Token::Ordinal(n, Some(unit), form) => {
+                let noun = Words::from(unit.clone());
+                self.render_pattern(
+                    &Pattern::UnitOrdinal(unit.clone()),
+                    s,
+                    |slot, s| match slot {
+                        "nth" => Some(self.render_ordinal(*n, &noun, *form, usage, s)),
+                        "unit" => Some(self.write_word(
+                            &noun,
+                            self.translate_in(&noun, usage).unwrap_or_default(),
+                            s,
+                        )),
+                        _ => None,
+                    },
+                )
+            }
+            Token::Ordinal(day, None, form) => {
+                self.render_pattern(&Pattern::DayOfMonth, s, |slot, s| match slot {
+                    "count" => Some(self.numbering().write_number(*day, s)),
+                    "nth" => Some(self.render_ordinal(*day, &Words::Day, *form, usage, s)),
+                    _ => None,
+                })
+            }
+            Token::Modified(modifier, noun) => {
+                let pattern = self.modified_pattern(*modifier, noun);
+                self.render_pattern(&pattern, s, |slot, s| match slot {
+                    "modifier" => {
+                        Some(s.write_str(self.modifier(*modifier, noun).unwrap_or_default()))
+                    }
+                    "noun" => Some(self.write_word(
+                        noun,
+                        self.translate_in(noun, usage).unwrap_or_default(),
+                        s,
+                    )),
+                    _ => None,
+                })
+            }
+            Token::List(style, items) => self.render_list(*style, ListPart::Start, items, usage, s),
+            Token::Phrase(pattern, inner) => self.render_pattern(pattern, s, |slot, s| {
+                (slot == "duration").then(|| self.render_in(inner, pattern.usage(), s))
+            }),
+        }
+    }
+
+    /// Write the translation of a word, capitalized if the [CasingRules] always capitalize it.
+    fn write_word<W: std::fmt::Write>(
+        &self,
+        word: &Words,
+        text: &str,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        let rules = self.casing_rules();
+        match rules.capitalizes(word) {
+            true => rules.write_capitalized(text, s),
+            false => s.write_str(text),
+        }
+    }
+
+    /// Write the number of a quantity in its [NumberForm], falling back to digits.
+    fn render_count<W: std::fmt::Write>(
+        &self,
+        count: i64,
+        unit: &TimeBoundary,
+        form: NumberForm,
+        usage: Option<Usage>,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        let noun = Words::from(unit.clone());
+        let spelled = match form {
+            NumberForm::Digits => None,
+            NumberForm::Article => match self.article_in(&noun, usage) {
+                Some(article) if !article.is_empty() => return s.write_str(article),
+                _ => self.cardinal_in(count, self.gender(&noun), usage),
+            },
+            NumberForm::Spelled => self.cardinal_in(count, self.gender(&noun), usage),
+        };
+
+        match spelled {
+            Some(words) => s.write_str(&words),
+            None => self.numbering().write_number(count, s),
+        }
+    }
+
+    /// Write an ordinal agreeing with a noun, spelled out unless the [NumberForm] is digits or
+    /// the locale cannot spell it, and otherwise in digits with its suffix.
+    fn render_ordinal<W: std::fmt::Write>(
+        &self,
+        n: i64,
+        noun: &Words,
+        form: NumberForm,
+        usage: Option<Usage>,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        let gender = self.gender(noun);
+        let spelled = match form {
+            NumberForm::Digits => None,
+            NumberForm::Spelled | NumberForm::Article => self.ordinal_in(n, gender, usage),
+        };
+
+        match spelled {
+            Some(words) => s.write_str(&words),
+            None => {
+                self.numbering().write_number(n, s)?;
+                s.write_str(self.suffix_in(n, gender, usage).unwrap_or_default())
+            }
+        }
+    }
+
+    /// Render a list. `part` joins the first item to the rest: [ListPart::Start] for a whole
+    /// list, [ListPart::Middle] once nested.
+    fn render_list<W: std::fmt::Write>(
+        &self,
+        style: ListStyle,
+        part: ListPart,
+        items: &[Template],
+        usage: Option<Usage>,
+        s: &mut W,
+    ) -> std::fmt::Result {
+        let Some(part) = part.joining(items.len()) else {
+            return match items.first() {
+                Some(item) => self.render_in(item, usage, s),
+                None => Ok(()),
+            };
+        };
+
+        self.render_pattern(&Pattern::List(style, part), s, |slot, s| match slot {
+            "0" => Some(self.render_in(&items[0], usage, s)),
+            "1" => Some(self.render_list(style, ListPart::Middle, &items[1..], usage, s)),
+            _ => None,
+        })
+    }
+
+    /// Render a sentence pattern, asking `fill` to write each slot.
+    fn render_pattern<W: std::fmt::Write>(
+        &self,
+        pattern: &Pattern,
+        s: &mut W,
+        fill: impl Fn(&str, &mut W) -> Option<std::fmt::Result>,
+    ) -> std::fmt::Result {
+        let default;
+        let template = match self.pattern(pattern) {
+            Some(template) => template,
+            None => {
+                default = pattern.default_template();
+                &default
+            }
+        };
+
+        for token in template.iter() {
+            match token {
+                Token::Unknown(slot) => {
+                    if let Some(result) = fill(slot, s) {
+                        result?
+                    }
+                }
+                token => self.render_token(token, None, s)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A writer which capitalizes the first letter written through it, for
+/// [Translator::render_capitalized].
+struct CapitalizeFirst<'w, W> {
+    inner: &'w mut W,
+    rules: CasingRules,
+    pending: bool,
+}
+
+impl<W: std::fmt::Write> std::fmt::Write for CapitalizeFirst<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if !self.pending {
+            return self.inner.write_str(s);
+        }
+
+        let text = s.trim_start();
+        self.inner.write_str(&s[..s.len() - text.len()])?;
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        self.pending = false;
+        self.rules.write_capitalized(text, self.inner)
+    }
+}
+
+/// The form for a [PluralCategory] in the `$(one{…}other{…})$` of a cardinal rule, else its
+/// `other` form.
+fn plural_form(forms: &str, category: PluralCategory) -> Option<&str> {
+    let mut other = None;
+    let mut rest = forms;
+    while let Some((name, tail)) = rest.split_once('{') {
+        let (form, tail) = tail.split_once('}')?;
+        match name.trim().parse() {
+            Ok(found) if found == category => return Some(form),
+            Ok(PluralCategory::Other) => other = Some(form),
+            _ => {}
+        }
+        rest = tail;
+    }
+
+    other
+}
+
+fn push_unique<T: PartialEq>(list: &mut Vec<T>, item: T) {
+    if !list.contains(&item) {
+        list.push(item)
     }
 }
 
@@ -201,7 +1560,7 @@ mod tests {
         use crate::enums::Words;
 
         assert_eq!(
-            "Yesterday",
+            "yesterday",
             DEFAULT_TRANSLATION.translate(&Words::Yesterday).unwrap()
         );
     }
@@ -211,11 +1570,15 @@ mod tests {
         use super::DEFAULT_TRANSLATION;
 
         assert_eq!(
-            "Yesterday at Noon",
+            "yesterday at noon",
             DEFAULT_TRANSLATION
                 .format("%{yesterday} %{at} %{noon}")
                 .unwrap()
         );
+        assert_eq!(
+            "last Monday",
+            DEFAULT_TRANSLATION.format("%{last} %{monday}").unwrap()
+        );
         assert_eq!("", DEFAULT_TRANSLATION.format("%{poop}").unwrap());
         assert_eq!("", DEFAULT_TRANSLATION.format("%{}").unwrap());
         assert_eq!("%", DEFAULT_TRANSLATION.format("%%").unwrap());
@@ -226,4 +1589,973 @@ mod tests {
         assert!(DEFAULT_TRANSLATION.format("%{").is_err());
         assert!(DEFAULT_TRANSLATION.format("%}").is_err());
     }
+
+    #[test]
+    fn test_format_checked() {
+        use super::*;
+        use crate::translator;
+
+        let translator = translator!((Words::Yesterday, "yesterday"), (Words::At, "at"));
+        assert_eq!(
+            "yesterday at",
+            translator.format_checked("%{yesterday} %{at}").unwrap()
+        );
+        assert_eq!(
+            Err(TranslationError::Incomplete {
+                missing: vec![Words::Noon],
+                unknown: vec!["poop".to_string(), "".to_string()],
+            }),
+            translator.format_checked("%{yesterday} %{at} %{noon} %{poop} %{noon} %{}")
+        );
+        assert!(matches!(
+            translator.format_checked("%{"),
+            Err(TranslationError::InvalidFormat(_))
+        ));
+
+        let translator = translator.with_fallback(translator!((Words::Noon, "midday")));
+        assert_eq!(
+            "yesterday at midday",
+            translator
+                .format_checked("%{yesterday} %{at} %{noon}")
+                .unwrap()
+        );
+        assert_eq!("", translator.format("%{midnight}").unwrap());
+    }
+
+    #[test]
+    fn test_layered() {
+        use super::*;
+        use crate::translation_map;
+
+        let base = translation_map!((Words::Day, "day"), (Words::Ago, "ago"), (Words::In, "in"));
+        let language = translation_map!((Words::Day, "jour"), (Words::Ago, "il y a"));
+        let overrides = translation_map!((Words::Day, "journée"));
+
+        let translator = Translator::layered([language.clone(), base.clone()]).unwrap();
+        assert_eq!(
+            "jour il y a in",
+            translator.format("%{day} %{ago} %{in}").unwrap()
+        );
+
+        let translator = translator.with_overrides(overrides.clone());
+        assert_eq!(
+            "journée il y a in",
+            translator.format("%{day} %{ago} %{in}").unwrap()
+        );
+
+        let translator = Translator::new(overrides)
+            .with_fallback(Translator::new(language))
+            .with_fallback(Translator::new(base));
+        assert_eq!(
+            "journée il y a in",
+            translator.format("%{day} %{ago} %{in}").unwrap()
+        );
+
+        assert!(Translator::layered([]).is_none());
+    }
+
+    #[test]
+    fn test_owned() {
+        use super::*;
+        use crate::translation_map;
+
+        fn shareable<T: Send + Sync + 'static>(_: &T) {}
+
+        let translator = {
+            let day = String::from("jour");
+            let ordinal = String::from("deuxième");
+            Translator::new(translation_map!(
+                (Words::Day, day.as_str()),
+                (Words::Variant(Usage::Past, Box::new(Words::Day)), "journée"),
+                (Words::Ordinal(2, None), ordinal.as_str()),
+                (Words::Pattern(Pattern::Past), "il y a %{duration}")
+            ))
+            .with_fallback(Translator::new(translation_map!((Words::Ago, "ago"))))
+            .into_owned()
+        };
+        shareable(&translator);
+        assert_eq!(Some("jour"), translator.translate(&Words::Day));
+        assert_eq!(
+            Some("journée"),
+            translator.translate_in(&Words::Day, Some(Usage::Past))
+        );
+        assert_eq!(Some("ago"), translator.translate(&Words::Ago));
+        assert_eq!(Some("deuxième".to_string()), translator.ordinal(2));
+        assert!(translator.pattern(&Pattern::Past).is_some());
+
+        let translator = Translator::from_owned(OwnedTranslationMap::from([
+            (Words::Day, Arc::from("día")),
+            (Words::Ago, Arc::from("hace")),
+        ]));
+        let translator = Arc::new(translator);
+        let shared = translator.clone();
+        assert_eq!(
+            "día hace",
+            std::thread::spawn(move || shared.format("%{day} %{ago}").unwrap())
+                .join()
+                .unwrap()
+        );
+
+        #[cfg(feature = "translation")]
+        {
+            let yaml = String::from("days: \"jours\\u00A0\"");
+            let translator = Translator::from_yaml_owned(&yaml).unwrap();
+            drop(yaml);
+            shareable(&translator);
+            assert_eq!(Some("jours\u{a0}"), translator.translate(&Words::DayPlural));
+            assert!(Translator::from_yaml_owned("days: [jours]").is_err());
+        }
+    }
+
+    #[cfg(feature = "translation")]
+    #[test]
+    fn test_load_locale() {
+        use super::*;
+
+        assert_eq!(vec!["en-GB", "en", "C"], locale_chain("en-GB"));
+        assert_eq!(
+            vec!["zh-Hant-TW", "zh-Hant", "zh", "C"],
+            locale_chain("zh-Hant-TW")
+        );
+        assert_eq!(vec!["C"], locale_chain("C"));
+
+        let translator = crate::load_locale!("xx-YY");
+        assert_eq!(Some("days"), translator.translate(&Words::DayPlural));
+
+        let translator = Translator::from_yaml("days: jours").unwrap();
+        assert_eq!(Some("jours"), translator.translate(&Words::DayPlural));
+        assert!(Translator::from_yaml("days: [jours]").is_err());
+    }
+
+    #[test]
+    fn test_numbering() {
+        use super::*;
+        use crate::translation_map;
+
+        let arabic = Translator::new(translation_map!(
+            (Words::Language, "ar"),
+            (Words::Numbering, "arab"),
+            (Words::Day, "يوم"),
+            (Words::DayPlural, "أيام"),
+            (Words::Ago, "منذ")
+        ));
+        assert_eq!(NumberingSystem::Arab, arabic.numbering());
+        assert_eq!("منذ ٣ أيام", arabic.format("%{ago} 3 %{days}").unwrap());
+
+        let mut template = Template::default();
+        template.quantity(45, TimeBoundary::Day);
+        template.literal(" / ");
+        template.number(2024);
+        assert_eq!("٤٥ أيام / ٢٠٢٤", arabic.render(&template));
+
+        let latin = arabic.with_numbering(NumberingSystem::Latn);
+        assert_eq!("45 أيام / 2024", latin.render(&template));
+        let latin = latin.with_overrides(translation_map!((Words::Day, "يوم")));
+        assert_eq!(NumberingSystem::Latn, latin.numbering());
+
+        let thai = Translator::new(translation_map!((Words::Numbering, "thai")));
+        assert_eq!("๒๐๒๔", thai.format("2024").unwrap());
+        let unknown = Translator::new(translation_map!((Words::Numbering, "roman")));
+        assert_eq!(NumberingSystem::Latn, unknown.numbering());
+    }
+
+    #[test]
+    fn test_cardinals() {
+        use super::*;
+        use crate::{
+            approximate::{format_generator::FormatGenerator, ApproximateState, StateCollection},
+            translation_map, CoarseRoundFormat,
+        };
+
+        let english = Translator::new(translation_map!(
+            (Words::Cardinal(0, None), "zero"),
+            (Words::Cardinal(1, None), "one"),
+            (Words::Cardinal(2, None), "two"),
+            (Words::Cardinal(3, None), "three"),
+            (Words::Cardinal(4, None), "four"),
+            (Words::Cardinal(5, None), "five"),
+            (Words::Cardinal(9, None), "nine"),
+            (Words::Cardinal(10, None), "ten"),
+            (Words::Cardinal(40, None), "forty[->>]"),
+            (Words::Cardinal(100, None), "<< hundred[ >>]"),
+            (Words::Cardinal(1000, None), "<< thousand[ >>]"),
+            (Words::A, "a"),
+            (Words::Article(Box::new(Words::Hour)), "an"),
+            (Words::Hour, "hour"),
+            (Words::Day, "day"),
+            (Words::DayPlural, "days"),
+            (Words::MinutePlural, "minutes"),
+            (Words::And, "and"),
+            (Words::Ago, "ago")
+        ));
+
+        let cases = [
+            (0, "zero"),
+            (9, "nine"),
+            (40, "forty"),
+            (45, "forty-five"),
+            (100, "one hundred"),
+            (345, "three hundred forty-five"),
+            (4_005, "four thousand five"),
+            (40_100, "forty thousand one hundred"),
+        ];
+        for (number, expected) in cases {
+            assert_eq!(Some(expected.to_string()), english.cardinal(number));
+        }
+
+        assert_eq!(None, english.cardinal(-1));
+        // no rule below ten can divide
+        let broken = Translator::new(translation_map!((Words::Cardinal(0, None), "<<")));
+        assert_eq!(None, broken.cardinal(5));
+
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::InPast(true));
+        states.push(ApproximateState::Value(TimeBoundary::Hour, 1));
+        states.push(ApproximateState::Value(TimeBoundary::Day, 9));
+        states.push(ApproximateState::Value(TimeBoundary::Minute, 45));
+
+        let mut format = CoarseRoundFormat::default()
+            .with_spelling(10)
+            .with_articles();
+        format.add(states.clone());
+        let template = format.template();
+        assert_eq!(
+            Ok("an hour, nine days and 45 minutes ago".to_string()),
+            english.render_checked(&template)
+        );
+        assert_eq!(
+            "%{hour_article} %{hour}, 9 %{days} %{and} 45 %{minutes} %{ago}",
+            template.to_string()
+        );
+
+        let mut format = CoarseRoundFormat::default().with_spelling(100);
+        format.add(states);
+        assert_eq!(
+            "one hour, nine days and forty-five minutes ago",
+            english.render(&format.template())
+        );
+
+        let russian = Translator::new(translation_map!(
+            (Words::Language, "ru"),
+            (Words::Cardinal(1, None), "один"),
+            (Words::Cardinal(1, Some(Gender::Feminine)), "одна"),
+            (Words::Cardinal(2, None), "два"),
+            (Words::Cardinal(2, Some(Gender::Feminine)), "две"),
+            (Words::Cardinal(5, None), "пять"),
+            (Words::Cardinal(20, None), "двадцать[ >>]"),
+            (Words::Cardinal(1000, None), "тысяча[ >>]"),
+            (
+                Words::Cardinal(2000, None),
+                "<feminine< $(one{тысяча}few{тысячи}other{тысяч})$[ >>]"
+            ),
+            (
+                Words::Variant(
+                    Usage::Past,
+                    Box::new(Words::Cardinal(1, Some(Gender::Feminine)))
+                ),
+                "одну"
+            )
+        ));
+        let cardinal = |n, gender, usage| russian.cardinal_in(n, gender, usage).unwrap();
+        assert_eq!("двадцать один", cardinal(21, None, None));
+        assert_eq!("двадцать одна", cardinal(21, Some(Gender::Feminine), None));
+        assert_eq!(
+            "двадцать одну",
+            cardinal(21, Some(Gender::Feminine), Some(Usage::Past))
+        );
+        assert_eq!("тысяча двадцать один", cardinal(1021, None, None));
+        assert_eq!("две тысячи пять", cardinal(2005, None, None));
+        assert_eq!("двадцать одна тысяча", cardinal(21_000, None, None));
+        assert_eq!("двадцать две тысячи", cardinal(22_000, None, None));
+        assert_eq!("двадцать пять тысяч", cardinal(25_000, None, None));
+
+        // an unknown gender spells nothing, while other brackets are text
+        let broken = Translator::new(translation_map!(
+            (Words::Cardinal(0, None), "<>$"),
+            (Words::Cardinal(10, None), "<sideways<")
+        ));
+        assert_eq!(Some("<>$".to_string()), broken.cardinal(5));
+        assert_eq!(None, broken.cardinal(20));
+
+        // cardinal rules are not mixed across layers
+        let layered =
+            Translator::new(translation_map!((Words::Hour, "Stunde"))).with_fallback(english);
+        assert_eq!(Some("forty".to_string()), layered.cardinal(40));
+        let layered = Translator::new(translation_map!((Words::Cardinal(2, None), "zwei")))
+            .with_fallback(layered);
+        assert_eq!(None, layered.cardinal(1));
+    }
+
+    #[test]
+    fn test_ordinals() {
+        use super::*;
+        use crate::{enums::PluralCategory, translation_map};
+
+        let english = Translator::new(translation_map!(
+            (Words::Suffix(PluralCategory::One, None), "st"),
+            (Words::Suffix(PluralCategory::Two, None), "nd"),
+            (Words::Suffix(PluralCategory::Few, None), "rd"),
+            (Words::Suffix(PluralCategory::Other, None), "th"),
+            (Words::Cardinal(1, None), "one"),
+            (Words::Cardinal(2, None), "two"),
+            (Words::Ordinal(1, None), "first"),
+            (Words::Ordinal(2, None), "second"),
+            (Words::Ordinal(3, None), "third"),
+            (Words::Ordinal(4, None), "fourth"),
+            (Words::Ordinal(20, None), "twent[y->>|ieth]"),
+            (Words::Ordinal(100, None), "<< hundred[ >>|th]"),
+            (Words::Week, "week")
+        ));
+
+        let cases = [
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (22, "22nd"),
+            (23, "23rd"),
+            (111, "111th"),
+        ];
+        for (day, expected) in cases {
+            let mut template = Template::default();
+            template.day_of_month(day);
+            assert_eq!(Ok(expected.to_string()), english.render_checked(&template));
+        }
+
+        let cases = [
+            (3, "third"),
+            (20, "twentieth"),
+            (23, "twenty-third"),
+            (100, "one hundredth"),
+            (200, "two hundredth"),
+            (201, "two hundred first"),
+        ];
+        for (number, expected) in cases {
+            assert_eq!(Some(expected.to_string()), english.ordinal(number));
+        }
+        assert_eq!(None, english.ordinal(-1));
+
+        let mut template = Template::default();
+        template.ordinal(2, TimeBoundary::Week);
+        template.literal(", ");
+        template.ordinal_as(23, Some(TimeBoundary::Week), NumberForm::Spelled);
+        template.literal(", ");
+        template.ordinal_as(4, None, NumberForm::Spelled);
+        assert_eq!(
+            "2nd week, twenty-third week, fourth",
+            english.render_checked(&template).unwrap()
+        );
+        assert_eq!(
+            "2%{suffix_two} %{week}, 23%{suffix_few} %{week}, 4%{suffix_other}",
+            template.to_string()
+        );
+
+        let spanish = Translator::new(translation_map!(
+            (Words::Language, "es"),
+            (Words::Suffix(PluralCategory::Other, None), ".º"),
+            (
+                Words::Suffix(PluralCategory::Other, Some(Gender::Feminine)),
+                ".ª"
+            ),
+            (Words::GenderOf(Box::new(Words::Week)), "feminine"),
+            (Words::Week, "semana"),
+            (Words::Pattern(Pattern::DayOfMonth), "%{count}"),
+            (Words::Cardinal(1, None), "uno")
+        ))
+        .with_fallback(english);
+        let mut template = Template::default();
+        template.ordinal(2, TimeBoundary::Week);
+        template.literal(" ");
+        template.day_of_month(1);
+        template.literal(" ");
+        template.ordinal_as(3, Some(TimeBoundary::Week), NumberForm::Spelled);
+        assert_eq!("2.ª semana 1 3.ª semana", spanish.render(&template));
+        assert_eq!(Some(".º"), spanish.suffix(1, Some(Gender::Masculine)));
+        // spanish cardinals keep english ordinal rules out
+        assert_eq!(None, spanish.ordinal(3));
+    }
+
+    #[test]
+    fn test_plural_forms() {
+        use super::*;
+        use crate::enums::PluralCategory;
+        use crate::translation_map;
+
+        let english = Translator::new(translation_map!(
+            (Words::Day, "day"),
+            (Words::DayPlural, "days")
+        ));
+        let russian = Translator::new(translation_map!(
+            (Words::Language, "ru"),
+            (
+                Words::PluralForm(TimeBoundary::Day, PluralCategory::One),
+                "день"
+            ),
+            (
+                Words::PluralForm(TimeBoundary::Day, PluralCategory::Few),
+                "дня"
+            ),
+            (
+                Words::PluralForm(TimeBoundary::Day, PluralCategory::Many),
+                "дней"
+            )
+        ));
+
+        let mut template = Template::default();
+        for count in [0, 1, 2, 5, 21] {
+            template.quantity(count, TimeBoundary::Day);
+            template.literal(",");
+        }
+
+        assert_eq!(PluralRules::English, english.plural_rules());
+        assert_eq!(
+            "0 days,1 day,2 days,5 days,21 days,",
+            english.render(&template)
+        );
+        assert_eq!(PluralRules::Russian, russian.plural_rules());
+        assert_eq!(
+            "0 дней,1 день,2 дня,5 дней,21 день,",
+            russian.render(&template)
+        );
+
+        let mut template = Template::default();
+        template.quantity(3, TimeBoundary::Hour);
+        assert_eq!(
+            Err(TranslationError::Incomplete {
+                missing: vec![Words::PluralForm(TimeBoundary::Hour, PluralCategory::Few)],
+                unknown: vec![],
+            }),
+            russian.render_checked(&template)
+        );
+    }
+
+    #[test]
+    fn test_patterns() {
+        use super::*;
+        use crate::{
+            approximate::{format_generator::FormatGenerator, ApproximateState, StateCollection},
+            translation_map, CoarseRoundFormat, FancyDurationFormat,
+        };
+
+        let french = Translator::new(translation_map!(
+            (Words::Language, "fr"),
+            (Words::Day, "jour"),
+            (Words::DayPlural, "jours"),
+            (Words::Hour, "heure"),
+            (Words::HourPlural, "heures"),
+            (Words::And, "et"),
+            (Words::Pattern(Pattern::Past), "il y a %{duration}"),
+            (Words::Pattern(Pattern::Future), "dans %{duration}")
+        ));
+        let japanese = Translator::new(translation_map!(
+            (Words::Language, "ja"),
+            (Words::DayPlural, "日"),
+            (Words::HourPlural, "時間"),
+            (Words::And, "と"),
+            (Words::Pattern(Pattern::Past), "%{duration}前"),
+            (Words::Pattern(Pattern::ShortFuture), "%{duration}後"),
+            (Words::Pattern(Pattern::Quantity), "%{count}%{unit}"),
+            (
+                Words::Pattern(Pattern::UnitQuantity(TimeBoundary::Hour)),
+                "%{count} %{unit}"
+            )
+        ));
+
+        let states = |past| {
+            let mut states = StateCollection::default();
+            states.push(ApproximateState::InPast(past));
+            states.push(ApproximateState::Value(TimeBoundary::Day, 2));
+            states.push(ApproximateState::Value(TimeBoundary::Hour, 1));
+            states
+        };
+
+        let mut coarse = CoarseRoundFormat::default();
+        coarse.add(states(true));
+        assert_eq!(
+            "il y a 2 jours et 1 heure",
+            french.render(&coarse.template())
+        );
+        assert_eq!("2日 と 1 時間前", japanese.render(&coarse.template()));
+        assert_eq!("2 %{days} %{and} 1 %{hour} %{ago}", coarse.format());
+
+        let mut coarse = CoarseRoundFormat::default();
+        coarse.add(states(false));
+        assert_eq!("dans 2 jours et 1 heure", french.render(&coarse.template()));
+        assert_eq!(
+            Err(TranslationError::Incomplete {
+                missing: vec![Words::FromNow],
+                unknown: vec![],
+            }),
+            japanese.render_checked(&coarse.template())
+        );
+
+        let mut fancy = FancyDurationFormat::default();
+        fancy.add(states(false));
+        assert_eq!("2d1h後", japanese.render(&fancy.template()));
+        assert_eq!("%{in} 2d1h", fancy.format());
+
+        for pattern in Pattern::all() {
+            for slot in pattern.slots() {
+                assert!(Words::from_str(slot).is_err(), "{}", slot);
+            }
+        }
+    }
+
+    #[test]
+    fn test_variants() {
+        use super::*;
+        use crate::{
+            approximate::{format_generator::FormatGenerator, ApproximateState, StateCollection},
+            enums::PluralCategory,
+            translation_map, CoarseRoundFormat,
+        };
+
+        let german = Translator::new(translation_map!(
+            (Words::Language, "de"),
+            (Words::Day, "Tag"),
+            (Words::DayPlural, "Tage"),
+            (
+                Words::Variant(Usage::Past, Box::new(Words::DayPlural)),
+                "Tagen"
+            ),
+            (
+                Words::Variant(Usage::Future, Box::new(Words::DayPlural)),
+                "Tagen"
+            ),
+            (Words::Pattern(Pattern::Past), "vor %{duration}"),
+            (Words::Pattern(Pattern::Future), "in %{duration}")
+        ));
+
+        let render = |past| {
+            let mut states = StateCollection::default();
+            if let Some(past) = past {
+                states.push(ApproximateState::InPast(past));
+            }
+            states.push(ApproximateState::Value(TimeBoundary::Day, 2));
+
+            let mut coarse = CoarseRoundFormat::default();
+            coarse.add(states);
+            german.render(&coarse.template())
+        };
+
+        assert_eq!("vor 2 Tagen", render(Some(true)));
+        assert_eq!("in 2 Tagen", render(Some(false)));
+        assert_eq!("2 Tage", render(None));
+
+        let russian = Translator::new(translation_map!(
+            (Words::Language, "ru"),
+            (Words::DayPlural, "дней"),
+            (
+                Words::PluralForm(TimeBoundary::Day, PluralCategory::Few),
+                "дня"
+            ),
+            (
+                Words::Variant(Usage::Past, Box::new(Words::DayPlural)),
+                "past"
+            ),
+            (
+                Words::Variant(
+                    Usage::Future,
+                    Box::new(Words::PluralForm(TimeBoundary::Day, PluralCategory::Few))
+                ),
+                "future"
+            )
+        ));
+
+        assert_eq!(
+            Some("дня"),
+            russian.quantity_in(2, &TimeBoundary::Day, Some(Usage::Past))
+        );
+        assert_eq!(
+            Some("past"),
+            russian.quantity_in(5, &TimeBoundary::Day, Some(Usage::Past))
+        );
+        assert_eq!(
+            Some("future"),
+            russian.quantity_in(2, &TimeBoundary::Day, Some(Usage::Future))
+        );
+        assert_eq!(
+            Some("дней"),
+            russian.quantity_in(5, &TimeBoundary::Day, Some(Usage::Future))
+        );
+        assert_eq!(
+            Some("past"),
+            russian.translate(&"past.days".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_agreement() {
+        use super::*;
+        use crate::{translation_map, translator};
+
+        let french = Translator::new(translation_map!(
+            (Words::Language, "fr"),
+            (Words::Week, "semaine"),
+            (Words::Month, "mois"),
+            (Words::Monday, "lundi"),
+            (Words::Last, "dernier"),
+            (Words::Next, "prochain"),
+            (
+                Words::Agreeing(Modifier::Last, Gender::Feminine),
+                "dernière"
+            ),
+            (
+                Words::Agreeing(Modifier::Next, Gender::Feminine),
+                "prochaine"
+            ),
+            (Words::GenderOf(Box::new(Words::Week)), "feminine"),
+            (Words::GenderOf(Box::new(Words::Month)), "masculine"),
+            (Words::GenderOf(Box::new(Words::Monday)), "masculine"),
+            (
+                Words::Pattern(Pattern::Modified(None, None)),
+                "%{noun} %{modifier}"
+            ),
+            (
+                Words::Pattern(Pattern::Modified(None, Some(Gender::Feminine))),
+                "la %{noun} %{modifier}"
+            ),
+            (
+                Words::Pattern(Pattern::Modified(None, Some(Gender::Masculine))),
+                "le %{noun} %{modifier}"
+            )
+        ));
+
+        let mut template = Template::default();
+        template.modified(Modifier::Last, Words::Week);
+        template.literal(", ");
+        template.modified(Modifier::Last, Words::Month);
+        template.literal(", ");
+        template.modified(Modifier::Next, Words::Monday);
+        assert_eq!(
+            "la semaine dernière, le mois dernier, le lundi prochain",
+            french.render(&template)
+        );
+        assert_eq!(
+            "last week, last month, next Monday",
+            translator!(
+                (Words::Week, "week"),
+                (Words::Month, "month"),
+                (Words::Monday, "monday"),
+                (Words::Last, "last"),
+                (Words::Next, "next")
+            )
+            .render(&template)
+        );
+        assert_eq!(
+            "%{last} %{week}, %{last} %{month}, %{next} %{monday}",
+            template.to_string()
+        );
+
+        let mut template = Template::default();
+        template.modified(Modifier::This, Words::Week);
+        assert_eq!(
+            Err(TranslationError::Incomplete {
+                missing: vec![Words::This],
+                unknown: vec![],
+            }),
+            french.render_checked(&template)
+        );
+
+        let french = french.with_overrides(translation_map!(
+            (Words::This, "ce"),
+            (Words::Agreeing(Modifier::This, Gender::Feminine), "cette"),
+            (
+                Words::Pattern(Pattern::Modified(Some(Modifier::This), None)),
+                "%{modifier} %{noun}"
+            )
+        ));
+        template.literal(", ");
+        template.modified(Modifier::Last, Words::Week);
+        template.literal(", ");
+        template.modified(Modifier::This, Words::Month);
+        assert_eq!(
+            "cette semaine, la semaine dernière, ce mois",
+            french.render(&template)
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        use super::*;
+        use crate::{
+            approximate::{format_generator::FormatGenerator, ApproximateState, StateCollection},
+            enums::{ListKind, ListWidth},
+            translation_map, CoarseRoundFormat,
+        };
+
+        let items = |n: i64| {
+            (1..=n)
+                .map(|x| {
+                    let mut item = Template::default();
+                    item.number(x);
+                    item
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let style = ListStyle::default();
+        let unit = ListStyle::new(ListKind::Unit, ListWidth::Wide);
+        let narrow = ListStyle::new(ListKind::Unit, ListWidth::Narrow);
+        let oxford = Translator::new(translation_map!(
+            (Words::And, "and"),
+            (
+                Words::Pattern(Pattern::List(style, ListPart::End)),
+                "%{0}, %{and} %{1}"
+            )
+        ));
+        let japanese = Translator::new(translation_map!(
+            (
+                Words::Pattern(Pattern::List(style, ListPart::Start)),
+                "%{0}、%{1}"
+            ),
+            (
+                Words::Pattern(Pattern::List(style, ListPart::Middle)),
+                "%{0}、%{1}"
+            ),
+            (
+                Words::Pattern(Pattern::List(style, ListPart::End)),
+                "%{0}、%{1}"
+            ),
+            (
+                Words::Pattern(Pattern::List(style, ListPart::Two)),
+                "%{0}、%{1}"
+            ),
+            (
+                Words::Pattern(Pattern::List(unit, ListPart::Two)),
+                "%{0}%{1}"
+            )
+        ));
+
+        for (n, english, oxford_comma, joined) in [
+            (0, "", "", ""),
+            (1, "1", "1", "1"),
+            (2, "1 %{and} 2", "1 and 2", "1、2"),
+            (3, "1, 2 %{and} 3", "1, 2, and 3", "1、2、3"),
+            (4, "1, 2, 3 %{and} 4", "1, 2, 3, and 4", "1、2、3、4"),
+        ] {
+            let mut template = Template::default();
+            template.list(style, items(n));
+            assert_eq!(english, template.to_string());
+            assert_eq!(oxford_comma, oxford.render(&template));
+            assert_eq!(joined, japanese.render(&template));
+        }
+
+        let mut template = Template::default();
+        template.list(narrow, items(2));
+        template.literal("/");
+        template.list(unit, items(3));
+        assert_eq!("1 2/1, 2, 3", template.to_string());
+        assert_eq!("12/1, 2, 3", japanese.render(&template));
+
+        let mut states = StateCollection::default();
+        states.push(ApproximateState::InPast(true));
+        states.push(ApproximateState::Value(TimeBoundary::Day, 2));
+        states.push(ApproximateState::Value(TimeBoundary::Hour, 1));
+
+        let mut coarse = CoarseRoundFormat::default().with_list_style(narrow);
+        coarse.add(states);
+        assert_eq!("2 %{days} 1 %{hour} %{ago}", coarse.format());
+    }
+
+    #[test]
+    fn test_capitalization() {
+        use super::*;
+        use crate::{enums::Capitalization, translation_map};
+
+        let english = Translator::new(translation_map!(
+            (Words::Yesterday, "yesterday"),
+            (Words::At, "at"),
+            (Words::Noon, "noon"),
+            (Words::Monday, "monday"),
+            (Words::In, "in"),
+            (Words::DayPlural, "days"),
+            (Words::Pattern(Pattern::Quantity), "%{count} %{unit}")
+        ));
+        let mut template = Template::default();
+        template.word(Words::Yesterday);
+        template.literal(" ");
+        template.word(Words::At);
+        template.literal(" ");
+        template.word(Words::Noon);
+        template.literal(", ");
+        template.word(Words::Monday);
+
+        assert_eq!("yesterday at noon, Monday", english.render(&template));
+        for capitalization in &Capitalization::all()[1..] {
+            assert_eq!(
+                "Yesterday at noon, Monday",
+                english.render_capitalized(&template, *capitalization)
+            );
+        }
+
+        let mut template = Template::default();
+        template.literal("  ");
+        template.word(Words::In);
+        template.literal(" ");
+        template.quantity(2, TimeBoundary::Day);
+        let english = english.with_capitalization(Capitalization::Standalone);
+        assert_eq!("  In 2 days", english.render(&template));
+        assert_eq!(
+            "  in 2 days",
+            english.render_capitalized(&template, Capitalization::MiddleOfSentence)
+        );
+
+        let german = Translator::new(translation_map!(
+            (Words::Language, "de"),
+            (Words::In, "in"),
+            (Words::DayPlural, "tagen"),
+            (Words::Pattern(Pattern::Quantity), "%{count} %{unit}")
+        ));
+        assert_eq!("  in 2 Tagen", german.render(&template));
+        assert_eq!(
+            "  In 2 Tagen",
+            german.render_capitalized(&template, Capitalization::BeginningOfSentence)
+        );
+
+        let turkish = Translator::new(translation_map!(
+            (Words::Language, "tr"),
+            (Words::In, "içinde")
+        ));
+        let mut template = Template::default();
+        template.word(Words::In);
+        assert_eq!("içinde", turkish.render(&template));
+        assert_eq!(
+            "İçinde",
+            turkish.render_capitalized(&template, Capitalization::UiListItem)
+        );
+    }
+
+    #[test]
+    fn test_messages() {
+        use super::*;
+        use crate::translation_map;
+
+        let english = Translator::new(translation_map!(
+            (Words::In, "in"),
+            (Words::DayPlural, "days"),
+            (Words::Cardinal(1, None), "one"),
+            (Words::Cardinal(2, None), "two"),
+            (Words::Suffix(PluralCategory::One, None), "st"),
+            (Words::Suffix(PluralCategory::Two, None), "nd"),
+            (Words::Suffix(PluralCategory::Other, None), "th")
+        ));
+        let message = "{name} {count, plural, offset:1 =0 {nobody} =1 {{name} alone} \
+                       one {{name} and # other} other {{name} and # others}}";
+        for (count, expected) in [
+            (0, "Ann nobody"),
+            (1, "Ann Ann alone"),
+            (2, "Ann Ann and 1 other"),
+            (3, "Ann Ann and 2 others"),
+        ] {
+            let arguments = Arguments::default()
+                .with("name", "Ann")
+                .with("count", count);
+            assert_eq!(
+                expected,
+                english.format_message(message, &arguments).unwrap()
+            );
+        }
+
+        let arguments = Arguments::default()
+            .with("n", 2)
+            .with("unit", Words::DayPlural);
+        assert_eq!(
+            "two days, the 2nd, 2.",
+            english
+                .format_message(
+                    "{n, spellout} {unit}, the {n, selectordinal, one {#st} other {#nd}}, {n}.",
+                    &arguments
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            "in 2 days: 2nd",
+            english
+                .format_message("%{in} {n} {unit}: {n, ordinal}", &arguments)
+                .unwrap()
+        );
+        assert_eq!(
+            "several days",
+            english
+                .format_message(
+                    "{unit, select, day {one day} days {several days} other {?}}",
+                    &arguments
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            Err(MessageError::ArgumentType {
+                position: 0,
+                name: "unit".into(),
+                expected: "a number"
+            }),
+            english.format_message("{unit, number}", &arguments)
+        );
+        assert_eq!(
+            Err(MessageError::Syntax {
+                position: 4,
+                reason: "unknown argument type"
+            }),
+            english.format_message("{n, date}", &arguments)
+        );
+        for (n, offset) in [(i64::MIN, "offset:1"), (i64::MAX, "offset:-1")] {
+            assert_eq!(
+                Err(MessageError::OutOfRange {
+                    position: 4,
+                    name: "n".into()
+                }),
+                english.format_message(
+                    &format!("n = {{n, plural, {} other {{#}}}}", offset),
+                    &Arguments::default().with("n", n)
+                )
+            );
+        }
+
+        let russian = Translator::new(translation_map!(
+            (Words::Language, "ru"),
+            (Words::Numbering, "arab")
+        ))
+        .with_capitalization(Capitalization::BeginningOfSentence);
+        let message: Message =
+            "{n, plural, one {# день} few {# дня} many {# дней} other {# дня}} {gender, select, \
+             feminine {прошла} other {прошёл}}"
+                .parse()
+                .unwrap();
+        for (n, expected) in [
+            (1, "١ день прошёл"),
+            (3, "٣ дня прошёл"),
+            (11, "١١ дней прошёл"),
+        ] {
+            let arguments = Arguments::default()
+                .with("n", n)
+                .with("gender", "masculine");
+            assert_eq!(
+                expected,
+                russian.render_message(&message, &arguments).unwrap()
+            );
+        }
+
+        let mut template = Template::default();
+        template.word(Words::In);
+        let mut s = String::new();
+        russian
+            .with_numbering(NumberingSystem::Latn)
+            .render_message_into(
+                &"{when} {n, plural, one {# день} other {# дня}}"
+                    .parse()
+                    .unwrap(),
+                &Arguments::default()
+                    .with("when", Words::Today)
+                    .with("n", 21)
+                    .with("unused", template),
+                &mut s,
+            )
+            .unwrap();
+        assert_eq!(" 21 день", s);
+    }
 }
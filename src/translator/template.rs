@@ -0,0 +1,460 @@
+use crate::{
+    enums::{ListPart, ListStyle, ListWidth, Modifier, OrdinalRules, Pattern, PluralRules, Words},
+    time_boundary::TimeBoundary,
+};
+use anyhow::{anyhow, Result};
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// A single piece of a [Template].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Token {
+    /// Text which is emitted as-is.
+    Literal(Cow<'static, str>),
+    /// A word which is looked up in the [Translator](crate::translator::Translator).
+    Word(Words),
+    /// A `%{…}` term which is not a known [Words]. These translate to nothing.
+    Unknown(String),
+    /// A number.
+    Number(i64),
+    /// A number of a unit, such as "3 days". The form of the unit is chosen when rendering, from
+    /// the [PluralRules](crate::enums::PluralRules) of the translator, and the number is written
+    /// in a [NumberForm].
+    Quantity(i64, TimeBoundary, NumberForm),
+    /// A number of a unit abbreviated to a [ListWidth], such as "3 hr." or "3h", placed in the
+    /// locale's [Pattern::AbbreviatedQuantity]. A wide abbreviation is a [Token::Quantity].
+    Abbreviated(i64, TimeBoundary, ListWidth),
+    /// An ordinal, such as "2nd week", placed in the locale's [Pattern::UnitOrdinal]; without a
+    /// unit, a day of the month such as "3rd", placed in its [Pattern::DayOfMonth]. The suffix
+    /// agrees with the gender of the unit, or of the day.
+    Ordinal(i64, Option<TimeBoundary>, NumberForm),
+    /// A template placed in the `%{duration}` slot of a locale's sentence [Pattern], such as
+    /// "il y a %{duration}".
+    Phrase(Pattern, Template),
+    /// A noun with a [Modifier] which agrees with its gender, such as "last week". Both are
+    /// placed in the locale's [Pattern::Modified].
+    Modified(Modifier, Words),
+    /// Items joined by the locale's [Pattern::List] for a [ListStyle], such as "3 days, 2 hours
+    /// and 1 minute".
+    List(ListStyle, Vec<Template>),
+}
+
+/// How the number of a [Token::Quantity] or [Token::Ordinal] is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NumberForm {
+    /// In digits of the translator's
+    /// [NumberingSystem](crate::enums::NumberingSystem): "3 days".
+    #[default]
+    Digits,
+    /// In words, if the locale has cardinal rules: "three days". See
+    /// [Translator::cardinal](crate::translator::Translator::cardinal).
+    Spelled,
+    /// As the unit's indefinite article, for a single unit: "an hour". Locales which leave the
+    /// article empty spell the number instead.
+    Article,
+}
+
+/// A compiled format: the `%{word}` syntax understood by
+/// [Translator::format](crate::translator::Translator::format), already split into [Token]s.
+/// [FormatGenerator](crate::approximate::format_generator::FormatGenerator)s can build these
+/// directly, and [Translator::render](crate::translator::Translator::render) translates them
+/// without parsing any strings.
+///
+/// Templates can be parsed from format strings, and displaying one yields the equivalent format
+/// string. Quantities and phrases display with English plurals and word order, as format strings
+/// have no way to defer the choice to the translator.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Template(Vec<Token>);
+
+impl Template {
+    /// Append a token.
+    #[inline]
+    pub fn push(&mut self, token: Token) {
+        self.0.push(token)
+    }
+
+    /// Append literal text.
+    #[inline]
+    pub fn literal(&mut self, s: impl Into<Cow<'static, str>>) {
+        self.0.push(Token::Literal(s.into()))
+    }
+
+    /// Append a word.
+    #[inline]
+    pub fn word(&mut self, word: Words) {
+        self.0.push(Token::Word(word))
+    }
+
+    /// Append a number.
+    #[inline]
+    pub fn number(&mut self, number: i64) {
+        self.0.push(Token::Number(number))
+    }
+
+    /// Append a number of a unit, in digits.
+    #[inline]
+    pub fn quantity(&mut self, count: i64, unit: TimeBoundary) {
+        self.0
+            .push(Token::Quantity(count, unit, NumberForm::Digits))
+    }
+
+    /// Append a number of a unit, written in a [NumberForm].
+    #[inline]
+    pub fn quantity_as(&mut self, count: i64, unit: TimeBoundary, form: NumberForm) {
+        self.0.push(Token::Quantity(count, unit, form))
+    }
+
+    /// Append a number of a unit abbreviated to a [ListWidth], in digits.
+    #[inline]
+    pub fn abbreviated(&mut self, count: i64, unit: TimeBoundary, width: ListWidth) {
+        self.0.push(Token::Abbreviated(count, unit, width))
+    }
+
+    /// Append an ordinal of a unit, in digits: "2nd week".
+    #[inline]
+    pub fn ordinal(&mut self, n: i64, unit: TimeBoundary) {
+        self.0
+            .push(Token::Ordinal(n, Some(unit), NumberForm::Digits))
+    }
+
+    /// Append a day of the month, in digits: "3rd".
+    #[inline]
+    pub fn day_of_month(&mut self, day: i64) {
+        self.0.push(Token::Ordinal(day, None, NumberForm::Digits))
+    }
+
+    /// Append an ordinal of a unit, or a day of the month without one, written in a
+    /// [NumberForm]. [NumberForm::Article] is treated as [NumberForm::Spelled].
+    #[inline]
+    pub fn ordinal_as(&mut self, n: i64, unit: Option<TimeBoundary>, form: NumberForm) {
+        self.0.push(Token::Ordinal(n, unit, form))
+    }
+
+    /// Append a template wrapped in a sentence pattern.
+    #[inline]
+    pub fn phrase(&mut self, pattern: Pattern, inner: Template) {
+        self.0.push(Token::Phrase(pattern, inner))
+    }
+
+    /// Append a noun with a modifier.
+    #[inline]
+    pub fn modified(&mut self, modifier: Modifier, noun: Words) {
+        self.0.push(Token::Modified(modifier, noun))
+    }
+
+    /// Append a list of items.
+    #[inline]
+    pub fn list(&mut self, style: ListStyle, items: Vec<Template>) {
+        self.0.push(Token::List(style, items))
+    }
+
+    /// Append all the tokens of another template.
+    #[inline]
+    pub fn append(&mut self, other: Template) {
+        self.0.extend(other.0)
+    }
+
+    /// Remove all tokens, keeping the allocation for re-use.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    /// Is the template empty?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the tokens in order.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Token> {
+        self.0.iter()
+    }
+
+    /// Split literal text into numbers and the text between them.
+    pub(crate) fn push_text(&mut self, text: &str) {
+        let mut rest = text;
+        while !rest.is_empty() {
+            let digits = rest
+                .find(|ch: char| !ch.is_ascii_digit())
+                .unwrap_or(rest.len());
+
+            let (token, tail) = if digits > 0 {
+                match rest[..digits].parse() {
+                    Ok(number) => (Token::Number(number), &rest[digits..]),
+                    Err(_) => (
+                        Token::Literal(rest[..digits].to_string().into()),
+                        &rest[digits..],
+                    ),
+                }
+            } else {
+                let text = rest
+                    .find(|ch: char| ch.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                (
+                    Token::Literal(rest[..text].to_string().into()),
+                    &rest[text..],
+                )
+            };
+
+            self.0.push(token);
+            rest = tail;
+        }
+    }
+}
+
+impl From<Vec<Token>> for Template {
+    fn from(value: Vec<Token>) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for Template {
+    type Err = anyhow::Error;
+
+    /// Parse a format. See [Translator::format](crate::translator::Translator::format) for the
+    /// syntax.
+    fn from_str(format: &str) -> Result<Self> {
+        let mut in_match = false;
+        let mut in_brace = false;
+
+        let mut template = Template::default();
+        let mut s = String::new();
+        let mut cap = String::new();
+
+        for ch in format.chars() {
+            match ch {
+                '%' => {
+                    if in_match && !in_brace {
+                        s.push(ch);
+                        in_match = false;
+                    } else {
+                        if in_brace || in_match {
+                            return Err(anyhow!("Invalid format (format attempted within format)"));
+                        }
+
+                        in_match = true;
+                        in_brace = false;
+                    }
+                }
+                '{' => {
+                    if !in_match {
+                        s.push(ch);
+                    } else {
+                        if in_brace {
+                            return Err(anyhow!(
+                                "Invalid format (open brace attempted within open brace)"
+                            ));
+                        }
+
+                        in_brace = true;
+                    }
+                }
+                '}' => {
+                    if !in_match {
+                        s.push(ch);
+                    } else {
+                        if !in_brace {
+                            return Err(anyhow!(
+                                "Invalid format (close brace attempted outside open brace)"
+                            ));
+                        }
+
+                        in_brace = false;
+                        in_match = false;
+
+                        template.push_text(&s);
+                        s.truncate(0);
+
+                        template.push(match Words::from_str(&cap) {
+                            Ok(word) => Token::Word(word),
+                            Err(_) => Token::Unknown(cap.clone()),
+                        });
+
+                        cap.truncate(0);
+                    }
+                }
+                _ => {
+                    if in_brace && in_match {
+                        cap.push(ch);
+                    } else {
+                        s.push(ch);
+                    }
+                }
+            }
+        }
+
+        if in_brace {
+            return Err(anyhow!("Invalid format (unclosed brace)"));
+        }
+
+        if in_match {
+            return Err(anyhow!("Invalid format (incomplete match)"));
+        }
+
+        template.push_text(&s);
+        Ok(template)
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Literal(s) => {
+                for (x, part) in s.split('%').enumerate() {
+                    if x > 0 {
+                        f.write_str("%%")?;
+                    }
+                    f.write_str(part)?;
+                }
+            }
+            Token::Word(word) => write!(f, "%{{{}}}", word)?,
+            Token::Unknown(s) => write!(f, "%{{{}}}", s)?,
+            Token::Number(number) => write!(f, "{}", number)?,
+            Token::Quantity(count, unit, form) => {
+                let word = Words::PluralForm(unit.clone(), PluralRules::English.category(*count))
+                    .fallback()
+                    .unwrap();
+
+                match form {
+                    NumberForm::Article => write!(
+                        f,
+                        "%{{{}}} %{{{}}}",
+                        Words::Article(Box::new(unit.clone().into())),
+                        word
+                    )?,
+                    _ => write!(f, "{} %{{{}}}", count, word)?,
+                }
+            }
+            Token::Abbreviated(count, unit, ListWidth::Wide) => write!(
+                f,
+                "{}",
+                Token::Quantity(*count, unit.clone(), NumberForm::Digits)
+            )?,
+            Token::Abbreviated(count, unit, width) => fmt_pattern(
+                f,
+                &Pattern::AbbreviatedQuantity(*width),
+                |slot, f| match slot {
+                    "count" => Some(write!(f, "{}", count)),
+                    "unit" => Some(write!(
+                        f,
+                        "%{{{}}}",
+                        Words::Abbreviation(unit.clone(), *width, None)
+                    )),
+                    _ => None,
+                },
+            )?,
+            Token::Ordinal(n, unit, _) => {
+                let suffix = Words::Suffix(OrdinalRules::English.category(*n), None);
+                write!(f, "{}%{{{}}}", n, suffix)?;
+                if let Some(unit) = unit {
+                    write!(f, " %{{{}}}", Words::from(unit.clone()))?
+                }
+            }
+            Token::Modified(modifier, noun) => {
+                write!(f, "%{{{}}} %{{{}}}", Words::from(*modifier), noun)?
+            }
+            Token::Phrase(pattern, inner) => fmt_pattern(f, pattern, |slot, f| {
+                (slot == "duration").then(|| write!(f, "{}", inner))
+            })?,
+            Token::List(style, items) => fmt_list(f, *style, ListPart::Start, items)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Display a pattern with its default template, asking `fill` to write each slot.
+fn fmt_pattern(
+    f: &mut std::fmt::Formatter<'_>,
+    pattern: &Pattern,
+    fill: impl Fn(&str, &mut std::fmt::Formatter<'_>) -> Option<std::fmt::Result>,
+) -> std::fmt::Result {
+    for token in pattern.default_template().iter() {
+        match token {
+            Token::Unknown(slot) => {
+                if let Some(result) = fill(slot, f) {
+                    result?
+                }
+            }
+            token => write!(f, "{}", token)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Display a list with its default patterns. `part` is the part joining the first item to the
+/// rest: [ListPart::Start] for a whole list, [ListPart::Middle] once nested.
+fn fmt_list(
+    f: &mut std::fmt::Formatter<'_>,
+    style: ListStyle,
+    part: ListPart,
+    items: &[Template],
+) -> std::fmt::Result {
+    let Some(part) = part.joining(items.len()) else {
+        return match items.first() {
+            Some(item) => write!(f, "{}", item),
+            None => Ok(()),
+        };
+    };
+
+    fmt_pattern(f, &Pattern::List(style, part), |slot, f| match slot {
+        "0" => Some(write!(f, "{}", items[0])),
+        "1" => Some(fmt_list(f, style, ListPart::Middle, &items[1..])),
+        _ => None,
+    })
+}
+
+impl std::fmt::Display for Template {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for token in &self.0 {
+            write!(f, "{}", token)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_template() {
+        use super::*;
+
+        let template: Template = "45 %{years}, 9%% %{poop}".parse().unwrap();
+        assert_eq!(
+            vec![
+                Token::Number(45),
+                Token::Literal(" ".into()),
+                Token::Word(Words::YearPlural),
+                Token::Literal(", ".into()),
+                Token::Number(9),
+                Token::Literal("% ".into()),
+                Token::Unknown("poop".into()),
+            ],
+            template.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!("45 %{years}, 9%% %{poop}", template.to_string());
+
+        let mut template = Template::default();
+        for count in [0, 1, 2] {
+            template.quantity(count, TimeBoundary::Day);
+            template.literal(", ");
+        }
+        assert_eq!("0 %{days}, 1 %{day}, 2 %{days}, ", template.to_string());
+
+        let mut inner = Template::default();
+        inner.quantity(3, TimeBoundary::Hour);
+        let mut template = Template::default();
+        template.phrase(Pattern::ShortFuture, inner.clone());
+        template.literal(", ");
+        template.phrase(Pattern::Past, inner);
+        assert_eq!("%{in} 3 %{hours}, 3 %{hours} %{ago}", template.to_string());
+
+        for bad in ["%", "%%%", "%{", "%}"] {
+            assert!(bad.parse::<Template>().is_err(), "{}", bad);
+        }
+    }
+}
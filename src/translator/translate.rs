@@ -0,0 +1,222 @@
+use super::{Template, Translator, DEFAULT_TRANSLATION};
+use crate::enums::Words;
+use anyhow::Result;
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Anything which translates [Words] and renders [Template]s. [Translator] is the standard
+/// implementation; others can wrap one to cache output, count lookups or consult a database
+/// first. The [from_now](crate::from_now) and [time_diff](crate::time_diff) macros and
+/// [Batch](crate::approximate::Batch) accept any implementation, including `&dyn Translate`.
+pub trait Translate {
+    /// Translate a word, if there is a translation for it.
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>>;
+
+    /// Render a compiled [Template] into a [std::fmt::Write]. An implementation which only looks
+    /// words up can render with a [Translator] built from its translations, such as one from
+    /// [Translator::from_owned], kept between calls rather than rebuilt for each.
+    fn render_into(&self, template: &Template, s: &mut dyn std::fmt::Write) -> std::fmt::Result;
+
+    /// Render a compiled [Template] into a new string.
+    fn render(&self, template: &Template) -> String {
+        let mut s = String::new();
+        // writing to a string never fails
+        self.render_into(template, &mut s).unwrap();
+        s
+    }
+
+    /// Parse a format and render it. See [Translator::format] for the syntax.
+    fn format(&self, format: &str) -> Result<String> {
+        Ok(self.render(&Template::from_str(format)?))
+    }
+}
+
+impl Translate for Translator<'_> {
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+        Translator::translate(self, word).map(Cow::Borrowed)
+    }
+
+    fn render_into(
+        &self,
+        template: &Template,
+        mut s: &mut dyn std::fmt::Write,
+    ) -> std::fmt::Result {
+        Translator::render_into(self, template, &mut s)
+    }
+}
+
+/// So that `&DEFAULT_TRANSLATION` can be given wherever a [Translate] is accepted.
+impl Translate for DEFAULT_TRANSLATION {
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+        Translate::translate(&**self, word)
+    }
+
+    fn render_into(&self, template: &Template, s: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        Translate::render_into(&**self, template, s)
+    }
+}
+
+impl<T: Translate + ?Sized> Translate for &T {
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+        (**self).translate(word)
+    }
+
+    fn render_into(&self, template: &Template, s: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        (**self).render_into(template, s)
+    }
+}
+
+impl<T: Translate + ?Sized> Translate for Box<T> {
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+        (**self).translate(word)
+    }
+
+    fn render_into(&self, template: &Template, s: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        (**self).render_into(template, s)
+    }
+}
+
+impl<T: Translate + ?Sized> Translate for Arc<T> {
+    fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+        (**self).translate(word)
+    }
+
+    fn render_into(&self, template: &Template, s: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        (**self).render_into(template, s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_translate() {
+        use super::*;
+        use crate::{
+            approximator, from_now, time_diff, translation_map, ApproximateFilter,
+            CoarseRoundFormat, TimeBoundary,
+        };
+        use chrono::{Duration, Local};
+        use std::cell::Cell;
+
+        /// Counts the templates it renders, and shouts.
+        struct Instrumented<'a> {
+            inner: Translator<'a>,
+            renders: Cell<usize>,
+        }
+
+        impl Translate for Instrumented<'_> {
+            fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+                Some(self.inner.translate(word)?.to_uppercase().into())
+            }
+
+            fn render_into(
+                &self,
+                template: &Template,
+                s: &mut dyn std::fmt::Write,
+            ) -> std::fmt::Result {
+                self.renders.set(self.renders.get() + 1);
+                s.write_str(&self.inner.render(template).to_uppercase())
+            }
+        }
+
+        let instrumented = Instrumented {
+            inner: Translator::new(translation_map!(
+                (Words::Ago, "ago"),
+                (Words::DayPlural, "days")
+            )),
+            renders: Cell::new(0),
+        };
+
+        let approx = approximator!(
+            CoarseRoundFormat::default(),
+            ApproximateFilter::Round(TimeBoundary::Day),
+            ApproximateFilter::Relative
+        );
+        let now = Local::now();
+        assert_eq!(
+            "2 DAYS AGO",
+            from_now!(now - Duration::days(2), approx, instrumented).unwrap()
+        );
+
+        let boxed: Box<dyn Translate + '_> = Box::new(&instrumented);
+        assert_eq!(
+            "3 DAYS AGO",
+            time_diff!(now - Duration::days(3), now, approx, boxed).unwrap()
+        );
+        assert_eq!(2, instrumented.renders.get());
+        assert_eq!(Some(Cow::from("DAYS")), boxed.translate(&Words::DayPlural));
+        assert_eq!("DAYS AGO", boxed.format("%{days} %{ago}").unwrap());
+
+        let translator: &dyn Translate = &DEFAULT_TRANSLATION;
+        assert_eq!(
+            DEFAULT_TRANSLATION.format("%{in} 2 %{days}").unwrap(),
+            translator.format("%{in} 2 %{days}").unwrap()
+        );
+
+        drop(boxed);
+        let shared = Arc::new(instrumented.inner);
+        assert_eq!(
+            Some(Cow::from("ago")),
+            Translate::translate(&shared, &Words::Ago)
+        );
+    }
+
+    #[test]
+    fn test_translate_lookup() {
+        use super::*;
+        use crate::{
+            approximator, enums::Pattern, from_now, ApproximateFilter, CoarseRoundFormat,
+            TimeBoundary,
+        };
+        use chrono::{Duration, Local};
+        use std::collections::HashMap;
+
+        /// Looks words up as a database of translations might, and renders with a [Translator]
+        /// built from the rows it loaded.
+        struct Lookup {
+            rows: HashMap<Words, Arc<str>>,
+            translator: Translator<'static>,
+        }
+
+        impl Lookup {
+            fn new(rows: HashMap<Words, Arc<str>>) -> Self {
+                let translator = Translator::from_owned(rows.clone());
+                Self { rows, translator }
+            }
+        }
+
+        impl Translate for Lookup {
+            fn translate(&self, word: &Words) -> Option<Cow<'_, str>> {
+                self.rows.get(word).map(|value| Cow::Borrowed(&**value))
+            }
+
+            fn render_into(
+                &self,
+                template: &Template,
+                s: &mut dyn std::fmt::Write,
+            ) -> std::fmt::Result {
+                Translate::render_into(&self.translator, template, s)
+            }
+        }
+
+        let lookup = Lookup::new(HashMap::from([
+            (Words::Pattern(Pattern::Past), "%{ago} %{duration}".into()),
+            (Words::Ago, "hace".into()),
+            (Words::DayPlural, "días".into()),
+            (Words::Numbering, "arab".into()),
+        ]));
+
+        let approx = approximator!(
+            CoarseRoundFormat::default(),
+            ApproximateFilter::Round(TimeBoundary::Day),
+            ApproximateFilter::Relative
+        );
+        assert_eq!(
+            "hace ٢ días",
+            from_now!(Local::now() - Duration::days(2), approx, lookup).unwrap()
+        );
+        assert_eq!("días, hace", lookup.format("%{days}, %{ago}").unwrap());
+        assert_eq!(Some(Cow::from("hace")), lookup.translate(&Words::Ago));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{enums::Words, translator::locale_chain};
+use anyhow::{anyhow, Result};
+use include_dir::Dir;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use std::str::FromStr;
+
+/// The result of checking a locale file against every [Words]; see [validate_locale].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocaleReport {
+    /// Words which have no translation in the file.
+    pub missing: Vec<Words>,
+    /// Keys in the file which are not [Words].
+    pub extra: Vec<String>,
+    /// Keys which appear more than once in the file. Only the last value would be used.
+    pub duplicate: Vec<String>,
+}
+
+impl LocaleReport {
+    /// Does the locale translate every word, with nothing extra or duplicated?
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.duplicate.is_empty()
+    }
+}
+
+impl std::fmt::Display for LocaleReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_complete() {
+            return f.write_str("complete");
+        }
+
+        let mut sections = Vec::new();
+        if !self.missing.is_empty() {
+            let missing: Vec<String> = self.missing.iter().map(|w| w.to_string()).collect();
+            sections.push(format!("missing: {}", missing.join(", ")));
+        }
+
+        if !self.extra.is_empty() {
+            sections.push(format!("extra: {}", self.extra.join(", ")));
+        }
+
+        if !self.duplicate.is_empty() {
+            sections.push(format!("duplicate: {}", self.duplicate.join(", ")));
+        }
+
+        f.write_str(&sections.join("; "))
+    }
+}
+
+/// Every key of a YAML mapping in the order it appears, including repeated ones.
+struct Entries(Vec<String>);
+
+impl<'de> Deserialize<'de> for Entries {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct EntriesVisitor;
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = Entries;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("Expecting a mapping of speakable-time format words")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut keys = Vec::new();
+                while let Some((key, _)) = map.next_entry::<String, serde_yaml::Value>()? {
+                    keys.push(key);
+                }
+
+                Ok(Entries(keys))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// Check the YAML contents of a locale file against every [Words], reporting missing, extra and
+/// duplicate keys. Words which are [optional](Words::is_optional) are never missing. An [Err] is returned if the file is not a YAML mapping at all.
+pub fn validate_locale(yaml: &str) -> Result<LocaleReport> {
+    let Entries(keys) = serde_yaml::from_str(yaml)?;
+    let mut report = LocaleReport::default();
+    let mut seen = Vec::new();
+
+    for key in keys {
+        match Words::from_str(&key) {
+            Ok(word) if seen.contains(&word) => {
+                if !report.duplicate.contains(&key) {
+                    report.duplicate.push(key)
+                }
+            }
+            Ok(word) => seen.push(word),
+            Err(_) => report.extra.push(key),
+        }
+    }
+
+    report.missing = Words::all()
+        .into_iter()
+        .filter(|word| !word.is_optional() && !seen.contains(word))
+        .collect();
+
+    Ok(report)
+}
+
+/// Check every `.yml` locale in an [include_dir::Dir], such as [LOCALES](crate::translator::LOCALES),
+/// returning a report for each file by name. A regional file like `en-GB.yml` is layered over its
+/// language as it is when loaded, so it is only missing the words which are also missing from
+/// `en.yml`; see [locale_chain](crate::translator::locale_chain). Every language must be complete
+/// on its own: `C.yml` is not counted, though it fills in for missing words at runtime.
+pub fn validate_locales(dir: &Dir) -> Result<Vec<(String, LocaleReport)>> {
+    let mut reports = Vec::new();
+
+    for file in dir.files() {
+        let path = file.path();
+        if path.extension().and_then(|x| x.to_str()) != Some("yml") {
+            continue;
+        }
+
+        let name = path.to_string_lossy().to_string();
+        let contents = file
+            .contents_utf8()
+            .ok_or_else(|| anyhow!("{} is not valid UTF-8", name))?;
+
+        reports.push((
+            name.clone(),
+            validate_locale(contents).map_err(|e| anyhow!("{}: {}", name, e))?,
+        ));
+    }
+
+    let mut layered = reports
+        .iter()
+        .map(|(name, report)| {
+            let locale = name.trim_end_matches(".yml");
+            let mut report = report.clone();
+            for parent in locale_chain(locale).iter().skip(1).filter(|x| *x != "C") {
+                let parent = format!("{}.yml", parent);
+                if let Some((_, parent)) = reports.iter().find(|(name, _)| *name == parent) {
+                    report.missing.retain(|word| parent.missing.contains(word));
+                }
+            }
+
+            (name.clone(), report)
+        })
+        .collect::<Vec<_>>();
+
+    layered.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(layered)
+}
+
+/// A test helper which panics, describing every problem found, unless every locale in the
+/// directory is complete. Use it in your own tests to keep shipped locales in sync with [Words]:
+///
+/// ```
+/// speakable_time::translator::assert_locales_complete(&speakable_time::translator::LOCALES);
+/// ```
+pub fn assert_locales_complete(dir: &Dir) {
+    let problems: Vec<String> = validate_locales(dir)
+        .unwrap()
+        .into_iter()
+        .filter(|(_, report)| !report.is_complete())
+        .map(|(name, report)| format!("{}: {}", name, report))
+        .collect();
+
+    assert!(
+        problems.is_empty(),
+        "Incomplete locales:\n{}",
+        problems.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_validate_locale() {
+        use super::*;
+
+        let report =
+            validate_locale("january: January\npoop: Poop\njanuary: Jan\nnoon: Noon\n").unwrap();
+        assert_eq!(vec!["poop".to_string()], report.extra);
+        assert_eq!(vec!["january".to_string()], report.duplicate);
+        assert!(!report.missing.contains(&Words::January));
+        assert!(!report.missing.contains(&Words::Noon));
+        assert!(report.missing.contains(&Words::Midnight));
+        assert!(!report.is_complete());
+
+        assert!(validate_locale("- january").is_err());
+    }
+
+    #[test]
+    fn test_embedded_locales_complete() {
+        super::assert_locales_complete(&crate::translator::LOCALES);
+    }
+
+    #[test]
+    fn test_incomplete_locales() {
+        use super::*;
+        use include_dir::{DirEntry, File};
+
+        let c = crate::translator::LOCALES
+            .get_file("C.yml")
+            .unwrap()
+            .contents();
+        let fr = format!("{}\nday: jour\n", std::str::from_utf8(c).unwrap())
+            .replace("\nday: day\n", "\n")
+            .replace("\nmidnight: midnight\n", "\n");
+        let entries = [
+            DirEntry::File(File::new("C.yml", c)),
+            DirEntry::File(File::new("de.yml", b"day: Tag\n")),
+            DirEntry::File(File::new("fr.yml", fr.as_bytes())),
+            DirEntry::File(File::new("fr-CA.yml", b"days: jours\n")),
+        ];
+        let dir = Dir::new("", &entries);
+
+        let reports = validate_locales(&dir).unwrap();
+        let report = |name: &str| &reports.iter().find(|(x, _)| x == name).unwrap().1;
+        assert!(report("C.yml").is_complete());
+        assert!(report("de.yml").missing.contains(&Words::Midnight));
+        assert_eq!(vec![Words::Midnight], report("fr.yml").missing);
+        assert_eq!(vec![Words::Midnight], report("fr-CA.yml").missing);
+
+        let panic = std::panic::catch_unwind(|| assert_locales_complete(&dir)).unwrap_err();
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.contains("de.yml: missing:"), "{}", message);
+        assert!(message.contains("fr.yml: missing: midnight"), "{}", message);
+        assert!(!message.contains("C.yml"), "{}", message);
+    }
+}